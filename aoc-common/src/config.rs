@@ -0,0 +1,96 @@
+use std::{fmt::Display, num::NonZeroUsize, str::FromStr};
+
+/// Puzzle constants a day takes from `--<name> <value>` flags. The default is the puzzle's own
+/// values, so flags are only needed for inputs such as the examples that use different ones.
+pub trait Config: Default {
+  /// The flag names without the leading dashes, each with what it sets.
+  const FLAGS: &'static [(&'static str, &'static str)];
+
+  /// Sets the constant behind `name`, one of [`Config::FLAGS`], from `value`.
+  fn set(&mut self, name: &str, value: &str) -> Result<(), String>;
+}
+
+/// For days without constants.
+impl Config for () {
+  const FLAGS: &'static [(&'static str, &'static str)] = &[];
+
+  fn set(&mut self, _: &str, _: &str) -> Result<(), String> {
+    Ok(())
+  }
+}
+
+/// `value` parsed for the flag `name`.
+pub fn parse_value<T>(name: &str, value: &str) -> Result<T, String>
+where
+  T: FromStr,
+  T::Err: Display,
+{
+  value.parse::<T>().map_err(|error| format!("invalid value \"{value}\" for \"--{name}\": {error}"))
+}
+
+/// Like [`parse_value`], for constants that must be at least 1.
+pub fn parse_positive(name: &str, value: &str) -> Result<NonZeroUsize, String> {
+  match parse_value::<usize>(name, value).map(NonZeroUsize::new) {
+    Ok(Some(value)) => Ok(value),
+    Ok(None) => Err(format!("invalid value \"{value}\" for \"--{name}\": expected a positive number")),
+    Err(error) => Err(error),
+  }
+}
+
+/// The defaults with each `(name, value)` flag applied in order.
+pub fn from_flags<C: Config>(flags: &[(String, String)]) -> Result<C, String> {
+  let mut config = C::default();
+
+  for (name, value) in flags {
+    if !C::FLAGS.iter().any(|&(flag, _)| flag == name) {
+      return Err(match C::FLAGS {
+        [] => format!("unknown option \"--{name}\", this day takes no options"),
+        flags => format!("unknown option \"--{name}\", expected {}", usage(flags)),
+      });
+    }
+
+    config.set(name, value)?;
+  }
+
+  Ok(config)
+}
+
+/// The flags for a usage message, e.g. `--steps <n>, --target <name>`.
+pub fn usage(flags: &[(&str, &str)]) -> String {
+  flags.iter().map(|(name, value)| format!("--{name} <{value}>")).collect::<Vec<String>>().join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[derive(Debug, Default, PartialEq)]
+  struct Steps {
+    steps: usize,
+  }
+
+  impl Config for Steps {
+    const FLAGS: &'static [(&'static str, &'static str)] = &[("steps", "n")];
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+      parse_positive(name, value).map(|steps| self.steps = steps.get())
+    }
+  }
+
+  fn flags(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs.iter().map(|&(name, value)| (name.to_string(), value.to_string())).collect()
+  }
+
+  #[test]
+  fn flags_override_defaults_in_order() {
+    assert_eq!(from_flags::<Steps>(&flags(&[])), Ok(Steps{ steps: 0 }));
+    assert_eq!(from_flags::<Steps>(&flags(&[("steps", "6"), ("steps", "10")])), Ok(Steps{ steps: 10 }));
+  }
+
+  #[test]
+  fn bad_flags_are_reported() {
+    assert_eq!(from_flags::<Steps>(&flags(&[("step", "6")])), Err(String::from("unknown option \"--step\", expected --steps <n>")));
+    assert_eq!(from_flags::<Steps>(&flags(&[("steps", "0")])), Err(String::from("invalid value \"0\" for \"--steps\": expected a positive number")));
+    assert_eq!(from_flags::<()>(&flags(&[("steps", "6")])), Err(String::from("unknown option \"--steps\", this day takes no options")));
+  }
+}
@@ -0,0 +1,251 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::math::crt;
+
+/// Shape of an eventually periodic sequence `x0, f(x0), f(f(x0)), ...`: the first `prefix`
+/// states never come back, after which the next `length` states repeat forever.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+  pub prefix: usize,
+  pub length: usize,
+}
+
+impl Cycle {
+  /// Index in `0..prefix + length` of the state equal to the one reached after `steps` steps.
+  pub fn reduce(&self, steps: usize) -> usize {
+    if steps < self.prefix {
+      steps
+    } else {
+      self.prefix + (steps - self.prefix) % self.length
+    }
+  }
+}
+
+/// Brent's algorithm. Keeps only a couple of states around, at the cost of calling `step` roughly
+/// three times per state.
+pub fn brent<T, F>(start: &T, step: F) -> Cycle
+where
+  T: Clone + PartialEq,
+  F: FnMut(&T) -> T,
+{
+  match brent_within(start, step, usize::MAX) {
+    Some(cycle) => cycle,
+    None => unreachable!("an unlimited search always ends on the cycle"),
+  }
+}
+
+/// Like [`brent`], but gives up with `None` once the cycle is known to need more than `limit`
+/// states, for sequences that may take too long to repeat.
+pub fn brent_within<T, F>(start: &T, mut step: F, limit: usize) -> Option<Cycle>
+where
+  T: Clone + PartialEq,
+  F: FnMut(&T) -> T,
+{
+  let mut power = 1;
+  let mut length = 1;
+  let mut tortoise = start.clone();
+  let mut hare = step(start);
+
+  while tortoise != hare {
+    if power == length {
+      if power > limit {
+        return None;
+      }
+
+      tortoise = hare.clone();
+      power *= 2;
+      length = 0;
+    }
+
+    hare = step(&hare);
+    length += 1;
+  }
+
+  let mut tortoise = start.clone();
+  let mut hare = start.clone();
+  for _ in 0..length {
+    hare = step(&hare);
+  }
+
+  let mut prefix = 0;
+  while tortoise != hare {
+    tortoise = step(&tortoise);
+    hare = step(&hare);
+    prefix += 1;
+  }
+
+  Some(Cycle{ prefix, length })
+}
+
+/// Remembers every state so `step` runs once per state. Returns the cycle together with the
+/// states `0..prefix + length`, which [`Cycle::reduce`] indexes into.
+pub fn find_cycle_hashed<T, F>(start: T, mut step: F) -> (Cycle, Vec<T>)
+where
+  T: Clone + Eq + Hash,
+  F: FnMut(&T) -> T,
+{
+  let mut seen = HashMap::new();
+  let mut states = vec![start];
+
+  loop {
+    let index = states.len() - 1;
+    let state = &states[index];
+
+    if let Some(&previous) = seen.get(state) {
+      states.pop();
+
+      return (Cycle{ prefix: previous, length: index - previous }, states);
+    }
+
+    seen.insert(state.clone(), index);
+
+    let next = step(state);
+    states.push(next);
+  }
+}
+
+/// The state after `steps` applications of `step`, skipping the full laps of `cycle`.
+pub fn advance<T, F>(start: &T, mut step: F, cycle: &Cycle, steps: usize) -> T
+where
+  T: Clone,
+  F: FnMut(&T) -> T,
+{
+  let mut state = start.clone();
+
+  for _ in 0..cycle.reduce(steps) {
+    state = step(&state);
+  }
+
+  state
+}
+
+/// The first step at which every sequence is at one of its hits. Each sequence is given as its
+/// cycle and the sorted indices in `0..prefix + length` of the states that count as hits.
+pub fn first_common_hit(sequences: &[(Cycle, Vec<usize>)]) -> Option<usize> {
+  let longest_prefix = sequences.iter().map(|(cycle, _)| cycle.prefix).max().unwrap_or(0);
+
+  for steps in 0..longest_prefix {
+    if sequences.iter().all(|(cycle, hits)| hits.binary_search(&cycle.reduce(steps)).is_ok()) {
+      return Some(steps);
+    }
+  }
+
+  // Every step combined so far is a residue modulo the lcm of the cycle lengths, so folding in one
+  // sequence at a time and dropping duplicates keeps at most `modulus` residues around.
+  let mut residues = vec![0];
+  let mut modulus = 1;
+
+  for (cycle, hits) in sequences {
+    let mut combined = vec![];
+    let mut combined_modulus = modulus;
+
+    for &residue in residues.iter() {
+      for &hit in hits.iter().filter(|&&hit| hit >= cycle.prefix) {
+        if let Some((next_residue, next_modulus)) = crt(&[(residue, modulus), (hit % cycle.length, cycle.length)]) {
+          combined.push(next_residue);
+          combined_modulus = next_modulus;
+        }
+      }
+    }
+
+    if combined.is_empty() {
+      return None;
+    }
+
+    combined.sort_unstable();
+    combined.dedup();
+    residues = combined;
+    modulus = combined_modulus;
+  }
+
+  // The residues are sorted, so the first one past the prefixes is the smallest answer unless
+  // lifting an earlier one past them by whole laps lands lower.
+  let lifted = |residue: usize| residue + (longest_prefix - residue).div_ceil(modulus) * modulus;
+
+  let first_past_prefix = residues.iter().copied().find(|&residue| residue >= longest_prefix);
+  let smallest_lifted = residues.iter().copied().take_while(|&residue| residue < longest_prefix).map(lifted).min();
+
+  first_past_prefix.into_iter().chain(smallest_lifted).min()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// 0, 1, 2, 3, 4, 5, 3, 4, 5, ...
+  fn step(&state: &usize) -> usize {
+    if state == 5 { 3 } else { state + 1 }
+  }
+
+  #[test]
+  fn brent_finds_prefix_and_length() {
+    assert_eq!(brent(&0, step), Cycle{ prefix: 3, length: 3 });
+    assert_eq!(brent(&4, step), Cycle{ prefix: 0, length: 3 });
+  }
+
+  #[test]
+  fn bounded_search_gives_up_on_long_cycles() {
+    let count = |&state: &u64| state + 1;
+
+    assert_eq!(brent_within(&0, count, 1000), None);
+    assert_eq!(brent_within(&0, step, 8), Some(Cycle{ prefix: 3, length: 3 }));
+  }
+
+  #[test]
+  fn hashed_matches_brent() {
+    let (cycle, states) = find_cycle_hashed(0, step);
+
+    assert_eq!(cycle, brent(&0, step));
+    assert_eq!(states, vec![0, 1, 2, 3, 4, 5]);
+  }
+
+  #[test]
+  fn advance_skips_laps() {
+    let cycle = brent(&0, step);
+
+    assert_eq!(advance(&0, step, &cycle, 2), 2);
+    assert_eq!(advance(&0, step, &cycle, 1_000_000_000), 4);
+    assert_eq!(cycle.reduce(1_000_000_000), 4);
+  }
+
+  #[test]
+  fn common_hit_in_prefix() {
+    let cycle = Cycle{ prefix: 3, length: 3 };
+
+    assert_eq!(first_common_hit(&[(cycle, vec![1, 4]), (cycle, vec![1, 5])]), Some(1));
+  }
+
+  #[test]
+  fn common_hit_with_offset_cycles() {
+    // hits at 3, 7, 11, ... and at 2, 5, 8, 11, ...
+    let first = (Cycle{ prefix: 3, length: 4 }, vec![3]);
+    let second = (Cycle{ prefix: 1, length: 3 }, vec![2]);
+
+    assert_eq!(first_common_hit(&[first, second]), Some(11));
+  }
+
+  #[test]
+  fn many_hits_per_cycle_stay_cheap() {
+    // Twelve sequences with fifteen hits each would be 15^12 combinations taken one at a time.
+    let sequences = (0..12)
+      .map(|index| {
+        let length = if index % 2 == 0 { 60 } else { 45 };
+
+        (Cycle{ prefix: index, length }, (index..index + length).filter(|hit| hit % 3 == 1 && (index > 0 || hit % 4 == 3)).collect())
+      })
+      .collect::<Vec<(Cycle, Vec<usize>)>>();
+
+    let brute_force = (0..10_000).find(|&steps| sequences.iter().all(|(cycle, hits)| hits.binary_search(&cycle.reduce(steps)).is_ok()));
+
+    assert!(brute_force.is_some());
+    assert_eq!(first_common_hit(&sequences), brute_force);
+  }
+
+  #[test]
+  fn no_common_hit() {
+    let first = (Cycle{ prefix: 0, length: 2 }, vec![0]);
+    let second = (Cycle{ prefix: 0, length: 2 }, vec![1]);
+
+    assert_eq!(first_common_hit(&[first, second]), None);
+  }
+}
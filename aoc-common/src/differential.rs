@@ -0,0 +1,85 @@
+use std::{fmt::{self, Debug, Display}, ops::Range};
+
+use crate::random::Rng;
+
+/// A generated input on which the fast and naive solvers disagree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch<T> {
+  pub seed: u64,
+  pub input: String,
+  pub fast: T,
+  pub naive: T,
+}
+
+impl<T: Debug> Display for Mismatch<T> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "seed {}: fast gave {:?}, naive gave {:?} for input\n{}", self.seed, self.fast, self.naive, self.input)
+  }
+}
+
+/// Generates one input per seed and runs both solvers on it, stopping at the first seed where
+/// their results differ.
+pub fn find_mismatch<T, G, F, N>(seeds: Range<u64>, generate: G, fast: F, naive: N) -> Option<Mismatch<T>>
+where
+  T: PartialEq,
+  G: Fn(&mut Rng) -> String,
+  F: Fn(&str) -> T,
+  N: Fn(&str) -> T,
+{
+  for seed in seeds {
+    let input = generate(&mut Rng::new(seed));
+
+    let fast = fast(&input);
+    let naive = naive(&input);
+
+    if fast != naive {
+      return Some(Mismatch{ seed, input, fast, naive });
+    }
+  }
+
+  None
+}
+
+/// Like [`find_mismatch`], but panics with the offending seed and input.
+pub fn assert_agree<T, G, F, N>(seeds: Range<u64>, generate: G, fast: F, naive: N)
+where
+  T: PartialEq + Debug,
+  G: Fn(&mut Rng) -> String,
+  F: Fn(&str) -> T,
+  N: Fn(&str) -> T,
+{
+  if let Some(mismatch) = find_mismatch(seeds, generate, fast, naive) {
+    panic!("{mismatch}");
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn generate(rng: &mut Rng) -> String {
+    rng.range(0..100).to_string()
+  }
+
+  fn square(input: &str) -> i64 {
+    let value = input.parse::<i64>().unwrap();
+
+    value * value
+  }
+
+  #[test]
+  fn reports_the_first_disagreement() {
+    let broken = |input: &str| if input.len() == 1 { square(input) } else { 0 };
+
+    let mismatch = find_mismatch(0..100, generate, broken, square).unwrap();
+
+    assert!(mismatch.input.len() > 1);
+    assert_eq!(mismatch.fast, 0);
+    assert_eq!(find_mismatch(0..mismatch.seed, generate, broken, square), None);
+  }
+
+  #[test]
+  fn agreeing_solvers_pass() {
+    assert_agree(0..100, generate, |input: &str| square(input).abs(), square);
+  }
+}
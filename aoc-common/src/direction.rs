@@ -0,0 +1,115 @@
+/// A cardinal direction on a grid whose rows grow downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+  Up,
+  Down,
+  Left,
+  Right,
+}
+
+impl Direction {
+  /// All four directions, clockwise from up.
+  pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+  pub fn turn_left(self) -> Direction {
+    match self {
+      Direction::Up => Direction::Left,
+      Direction::Left => Direction::Down,
+      Direction::Down => Direction::Right,
+      Direction::Right => Direction::Up,
+    }
+  }
+
+  pub fn turn_right(self) -> Direction {
+    match self {
+      Direction::Up => Direction::Right,
+      Direction::Right => Direction::Down,
+      Direction::Down => Direction::Left,
+      Direction::Left => Direction::Up,
+    }
+  }
+
+  pub fn reverse(self) -> Direction {
+    match self {
+      Direction::Up => Direction::Down,
+      Direction::Down => Direction::Up,
+      Direction::Left => Direction::Right,
+      Direction::Right => Direction::Left,
+    }
+  }
+
+  /// `(row, col)` offset of a single step.
+  pub fn delta(self) -> (isize, isize) {
+    match self {
+      Direction::Up => (-1, 0),
+      Direction::Down => (1, 0),
+      Direction::Left => (0, -1),
+      Direction::Right => (0, 1),
+    }
+  }
+
+  /// A distinct bit per direction, for packing a set of directions into one `u8`.
+  pub fn bit(self) -> u8 {
+    match self {
+      Direction::Up => 1,
+      Direction::Down => 2,
+      Direction::Left => 4,
+      Direction::Right => 8,
+    }
+  }
+
+  /// Accepts both `U`/`D`/`L`/`R` and the arrows `^`/`v`/`<`/`>`.
+  pub fn from_char(char: char) -> Option<Direction> {
+    match char {
+      'U' | '^' => Some(Direction::Up),
+      'D' | 'v' => Some(Direction::Down),
+      'L' | '<' => Some(Direction::Left),
+      'R' | '>' => Some(Direction::Right),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn turns_compose() {
+    for direction in Direction::ALL {
+      assert_eq!(direction.turn_left().turn_right(), direction);
+      assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+      assert_eq!(direction.reverse().reverse(), direction);
+    }
+  }
+
+  #[test]
+  fn all_is_clockwise() {
+    for (index, direction) in Direction::ALL.iter().enumerate() {
+      assert_eq!(direction.turn_right(), Direction::ALL[(index + 1) % 4]);
+    }
+  }
+
+  #[test]
+  fn reverse_negates_delta() {
+    for direction in Direction::ALL {
+      let (row, col) = direction.delta();
+
+      assert_eq!(direction.reverse().delta(), (-row, -col));
+    }
+  }
+
+  #[test]
+  fn bits_are_distinct() {
+    assert_eq!(Direction::ALL.iter().fold(0, |mask, direction| mask | direction.bit()), 0b1111);
+  }
+
+  #[test]
+  fn parses_letters_and_arrows() {
+    assert_eq!(Direction::from_char('U'), Direction::from_char('^'));
+    assert_eq!(Direction::from_char('v'), Some(Direction::Down));
+    assert_eq!(Direction::from_char('<'), Some(Direction::Left));
+    assert_eq!(Direction::from_char('R'), Some(Direction::Right));
+    assert_eq!(Direction::from_char('x'), None);
+  }
+}
@@ -0,0 +1,165 @@
+use std::{fmt, io};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+  Io(String),
+  /// Malformed input; `line` and `column` are 1-based, 0 when unknown. `length` is the number of
+  /// bytes of the offending token, 0 when unknown.
+  Parse { line: usize, column: usize, length: usize, message: String },
+  /// Well-formed input the solver cannot answer, e.g. a missing start node.
+  Unsolvable(String),
+  /// A part the solver does not answer yet, whatever the input.
+  NotImplemented,
+}
+
+/// Rounds `index` down to the start of the character it falls in.
+fn char_boundary(line: &str, index: usize) -> usize {
+  let mut index = index.min(line.len());
+
+  while !line.is_char_boundary(index) {
+    index -= 1;
+  }
+
+  index
+}
+
+impl Error {
+  pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Error {
+    Error::Parse{ line, column, length: 0, message: message.into() }
+  }
+
+  /// Sets the length of the offending token of a parse error.
+  pub fn spanning(self, length: usize) -> Error {
+    match self {
+      Error::Parse{ line, column, message, .. } => Error::Parse{ line, column, length, message },
+      error => error,
+    }
+  }
+
+  /// A parse error for `token`, which must be a slice of `line`; the column is
+  /// taken from its position within the line and the length from the token.
+  pub fn parse_at(line_number: usize, line: &str, token: &str, message: impl Into<String>) -> Error {
+    let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+    let column = if offset <= line.len() { offset + 1 } else { 0 };
+
+    Error::parse(line_number, column, message).spanning(if column == 0 { 0 } else { token.len() })
+  }
+
+  pub fn unsolvable(message: impl Into<String>) -> Error {
+    Error::Unsolvable(message.into())
+  }
+
+  /// Fills in the line of a parse error raised by a helper that only saw that line.
+  pub fn on_line(self, line_number: usize) -> Error {
+    match self {
+      Error::Parse{ line: 0, column, length, message } => Error::Parse{ line: line_number, column, length, message },
+      error => error,
+    }
+  }
+
+  /// Moves a parse error raised by a helper that only saw part of the input down by `lines`, the
+  /// number of lines before that part.
+  pub fn after_lines(self, lines: usize) -> Error {
+    match self {
+      Error::Parse{ line, column, length, message } if line > 0 => Error::Parse{ line: line + lines, column, length, message },
+      error => error,
+    }
+  }
+
+  /// The line of `contents` that a parse error points at, with carets under the offending token
+  /// like a compiler diagnostic, or under the whole line when the column is unknown. Missing lines
+  /// are shown as an empty line after the last. `None` for other errors and for lines outside
+  /// `contents`.
+  pub fn excerpt(&self, contents: &str) -> Option<String> {
+    let (line_number, column, length) = match self {
+      Error::Parse{ line, column, length, .. } if *line > 0 => (*line, *column, *length),
+      _ => return None,
+    };
+
+    let line = contents.lines().chain([""]).nth(line_number - 1)?;
+
+    let (start, end) = if column == 0 {
+      (line.len() - line.trim_start().len(), line.len())
+    } else {
+      let start = char_boundary(line, column - 1);
+      (start, char_boundary(line, start + length))
+    };
+
+    let indent = line[..start].chars().map(|char| if char == '\t' { '\t' } else { ' ' }).collect::<String>();
+    let carets = "^".repeat(line[start..end].chars().count().max(1));
+    let gutter = " ".repeat(line_number.to_string().len());
+
+    Some(format!("{gutter} |\n{line_number} | {line}\n{gutter} | {indent}{carets}\n"))
+  }
+}
+
+impl fmt::Display for Error {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Error::Io(message) => write!(f, "{message}"),
+      Error::Parse{ line: 0, message, .. } => write!(f, "parse error: {message}"),
+      Error::Parse{ line, column: 0, message, .. } => write!(f, "parse error on line {line}: {message}"),
+      Error::Parse{ line, column, message, .. } => write!(f, "parse error on line {line}, column {column}: {message}"),
+      Error::Unsolvable(message) => write!(f, "unsolvable input: {message}"),
+      Error::NotImplemented => write!(f, "not implemented"),
+    }
+  }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+  fn from(error: io::Error) -> Self {
+    Error::Io(error.to_string())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_at_uses_token_offset() {
+    let line = "Game 12: 3 blue";
+    let token = &line[5..7];
+
+    assert_eq!(Error::parse_at(4, line, token, "bad id"), Error::parse(4, 6, "bad id").spanning(2));
+  }
+
+  #[test]
+  fn on_line_only_fills_unknown_lines() {
+    assert_eq!(Error::parse(0, 3, "x").on_line(7), Error::parse(7, 3, "x"));
+    assert_eq!(Error::parse(2, 3, "x").on_line(7), Error::parse(2, 3, "x"));
+    assert_eq!(Error::unsolvable("x").on_line(7), Error::unsolvable("x"));
+  }
+
+  #[test]
+  fn after_lines_shifts_known_lines() {
+    assert_eq!(Error::parse(2, 3, "x").spanning(1).after_lines(5), Error::parse(7, 3, "x").spanning(1));
+    assert_eq!(Error::parse(0, 3, "x").after_lines(5), Error::parse(0, 3, "x"));
+  }
+
+  #[test]
+  fn display_includes_position() {
+    assert_eq!(Error::parse(3, 5, "unexpected \"x\"").to_string(), "parse error on line 3, column 5: unexpected \"x\"");
+    assert_eq!(Error::unsolvable("no start node").to_string(), "unsolvable input: no start node");
+    assert_eq!(Error::NotImplemented.to_string(), "not implemented");
+  }
+
+  #[test]
+  fn excerpt_underlines_the_token() {
+    let contents = "px{a<2006:qkq,rfg}\npv{a>1716:R,q<3:A}";
+
+    assert_eq!(Error::parse(2, 13, "unknown category \"q\"").spanning(1).excerpt(contents).unwrap(), "  |\n2 | pv{a>1716:R,q<3:A}\n  |             ^\n");
+    assert_eq!(Error::parse(1, 11, "x").spanning(3).excerpt(contents).unwrap(), "  |\n1 | px{a<2006:qkq,rfg}\n  |           ^^^\n");
+    assert_eq!(Error::parse(1, 0, "x").excerpt("  ab"), Some(String::from("  |\n1 |   ab\n  |   ^^\n")));
+    assert_eq!(Error::parse(3, 0, "missing parts").excerpt(contents), Some(String::from("  |\n3 | \n  | ^\n")));
+    assert_eq!(Error::parse(4, 1, "x").excerpt(contents), None);
+    assert_eq!(Error::unsolvable("x").excerpt(contents), None);
+  }
+
+  #[test]
+  fn excerpt_counts_characters_not_bytes() {
+    assert_eq!(Error::parse(1, 4, "x").spanning(2).excerpt("é=é").unwrap(), "  |\n1 | é=é\n  |   ^\n");
+  }
+}
@@ -0,0 +1,163 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::parser::Failure;
+
+/// Every integer in `text`, ignoring whatever surrounds them. A `-` right before the digits is
+/// kept as a sign unless it follows a letter or digit, so `x=-5` gives -5 and `10-20` gives 10, 20.
+/// Values that do not fit in `T` are reported at the offending number.
+pub fn integers<T>(text: &str) -> Result<Vec<T>, Failure<'_>>
+where
+  T: FromStr,
+  T::Err: Display,
+{
+  scan(text, false, false)
+}
+
+/// Like [`integers`], but digits separated only by whitespace belong to the same number, so
+/// `7  15   30` gives the single value 71530.
+pub fn kerned_integers<T>(text: &str) -> Result<Vec<T>, Failure<'_>>
+where
+  T: FromStr,
+  T::Err: Display,
+{
+  scan(text, true, false)
+}
+
+/// Like [`integers`], for text that must hold nothing but the numbers with whitespace and commas
+/// between them: `3, -1 4` gives 3, -1, 4 while `1x2` is refused at the `x`.
+pub fn separated_integers<T>(text: &str) -> Result<Vec<T>, Failure<'_>>
+where
+  T: FromStr,
+  T::Err: Display,
+{
+  scan(text, false, true)
+}
+
+/// Exactly `N` integers from `text`, separated as for [`separated_integers`].
+pub fn exactly<T, const N: usize>(text: &str) -> Result<[T; N], Failure<'_>>
+where
+  T: FromStr,
+  T::Err: Display,
+{
+  let values = separated_integers::<T>(text)?;
+
+  match <[T; N]>::try_from(values) {
+    Ok(values) => Ok(values),
+    Err(values) => Err(Failure::invalid(text, format!("expected {N} numbers, found {}", values.len())).spanning(text.len())),
+  }
+}
+
+/// With `strict`, anything but a separator between the numbers is refused.
+fn scan<T>(text: &str, kerning: bool, strict: bool) -> Result<Vec<T>, Failure<'_>>
+where
+  T: FromStr,
+  T::Err: Display,
+{
+  let bytes = text.as_bytes();
+  let mut values = vec![];
+  let mut index = 0;
+
+  while index < bytes.len() {
+    let is_sign = bytes[index] == b'-'
+      && bytes.get(index + 1).is_some_and(u8::is_ascii_digit)
+      && (index == 0 || !bytes[index - 1].is_ascii_alphanumeric());
+
+    if !is_sign && !bytes[index].is_ascii_digit() {
+      if strict && !is_separator(bytes[index]) {
+        return Err(Failure::invalid(&text[index..], "expected a number"));
+      }
+
+      index += 1;
+      continue;
+    }
+
+    let start = index;
+    let mut digits = String::new();
+
+    if is_sign {
+      digits.push('-');
+      index += 1;
+    }
+
+    loop {
+      while index < bytes.len() && bytes[index].is_ascii_digit() {
+        digits.push(bytes[index] as char);
+        index += 1;
+      }
+
+      let gap = bytes[index..].iter().take_while(|byte| byte.is_ascii_whitespace()).count();
+
+      if !kerning || gap == 0 || !bytes.get(index + gap).is_some_and(u8::is_ascii_digit) {
+        break;
+      }
+
+      index += gap;
+    }
+
+    match digits.parse::<T>() {
+      Ok(value) => values.push(value),
+      Err(error) => return Err(Failure::invalid(&text[start..], format!("invalid number \"{digits}\": {error}")).spanning(index - start)),
+    }
+
+    if strict && index < bytes.len() && !is_separator(bytes[index]) {
+      return Err(Failure::invalid(&text[index..], "expected whitespace or \",\" after a number"));
+    }
+  }
+
+  Ok(values)
+}
+
+fn is_separator(byte: u8) -> bool {
+  byte.is_ascii_whitespace() || byte == b','
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn keeps_signs_only_in_front_of_numbers() {
+    assert_eq!(integers::<i64>("p=-3,14 v=2,-1"), Ok(vec![-3, 14, 2, -1]));
+    assert_eq!(integers::<i64>("10-20 - 5"), Ok(vec![10, 20, 5]));
+    assert_eq!(integers::<u32>("no numbers here"), Ok(vec![]));
+  }
+
+  #[test]
+  fn separated_numbers_allow_only_whitespace_and_commas() {
+    assert_eq!(separated_integers::<i64>("-3,14 2, -1"), Ok(vec![-3, 14, 2, -1]));
+    assert_eq!(separated_integers::<u32>("  "), Ok(vec![]));
+
+    let failure = separated_integers::<u32>("0 3 1x2 15").unwrap_err();
+    assert_eq!((failure.at, failure.length), ("x2 15", 2));
+
+    let failure = separated_integers::<u32>("a b").unwrap_err();
+    assert_eq!((failure.at, failure.length, failure.message.as_str()), ("a b", 1, "expected a number"));
+
+    assert_eq!(separated_integers::<i64>("10-20").unwrap_err().at, "-20");
+    assert_eq!(separated_integers::<i64>("5 - 5").unwrap_err().at, "- 5");
+  }
+
+  #[test]
+  fn overflow_points_at_the_number() {
+    let text = "1 2 300";
+    let failure = integers::<u8>(text).unwrap_err();
+
+    assert_eq!(failure.at, "300");
+    assert_eq!(failure.length, 3);
+    assert!(integers::<u8>("-1").is_err());
+    assert_eq!(integers::<u128>("340282366920938463463374607431768211455"), Ok(vec![u128::MAX]));
+  }
+
+  #[test]
+  fn kerning_joins_digits_across_whitespace() {
+    assert_eq!(kerned_integers::<u64>("Time:      7  15   30"), Ok(vec![71530]));
+    assert_eq!(kerned_integers::<u64>("1 2, 3 4"), Ok(vec![12, 34]));
+  }
+
+  #[test]
+  fn exactly_checks_the_count() {
+    assert_eq!(exactly::<usize, 3>("1,0,1"), Ok([1, 0, 1]));
+    assert_eq!(exactly::<usize, 3>("1,0").unwrap_err().message, "expected 3 numbers, found 2");
+    assert_eq!(exactly::<usize, 3>("1 2 1 zz").unwrap_err().at, "zz");
+  }
+}
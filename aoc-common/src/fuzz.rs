@@ -0,0 +1,128 @@
+use std::{any::Any, ops::Range, panic::{self, AssertUnwindSafe}};
+
+use crate::{error::Error, input::normalize, random::Rng, solution::{Solution, solve}};
+
+/// Characters that tend to upset parsers: digits and signs, separators, and a multi-byte and a
+/// control character to catch slicing by byte offsets.
+const AWKWARD: [char; 14] = ['0', '9', '-', '+', ' ', '\n', ',', ':', '=', '#', '.', '@', 'é', '\0'];
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+  match payload.downcast::<String>() {
+    Ok(message) => *message,
+    Err(payload) => match payload.downcast::<&str>() {
+      Ok(message) => message.to_string(),
+      Err(_) => String::from("unknown panic"),
+    },
+  }
+}
+
+/// Whether a parse error's line is one of the lines of `contents` and its span ends on that line.
+/// Missing lines may be reported on the empty line after the last.
+fn points_into(error: &Error, contents: &str) -> bool {
+  match error {
+    Error::Parse{ line: 0, .. } => true,
+    Error::Parse{ line, column, length, .. } => {
+      match contents.lines().chain([""]).nth(line - 1) {
+        Some(text) => column + length <= text.len() + 1,
+        None => false,
+      }
+    },
+    _ => true,
+  }
+}
+
+/// Up to `length` random bytes, decoded lossily.
+fn noise(rng: &mut Rng, length: usize) -> String {
+  let bytes = (0..rng.below(length + 1)).map(|_| rng.next_u64() as u8).collect::<Vec<u8>>();
+
+  String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// A character from `chars` or an awkward one.
+fn pick(rng: &mut Rng, chars: &[char]) -> char {
+  if chars.is_empty() || rng.chance(1, 3) {
+    *rng.choose(&AWKWARD)
+  } else {
+    *rng.choose(chars)
+  }
+}
+
+/// `input` with a few characters deleted, replaced or inserted, and sometimes cut short.
+fn mutate(rng: &mut Rng, input: &str) -> String {
+  let mut chars = input.chars().collect::<Vec<char>>();
+  let alphabet = chars.clone();
+
+  for _ in 0..rng.range(1..5) {
+    let index = rng.below(chars.len() + 1);
+
+    match rng.below(8) {
+      0..=2 if index < chars.len() => {
+        chars.remove(index);
+      },
+      3..=5 if index < chars.len() => {
+        chars[index] = pick(rng, &alphabet);
+      },
+      6 => {
+        chars.truncate(index);
+      },
+      _ => {
+        chars.insert(index, pick(rng, &alphabet));
+      },
+    }
+  }
+
+  chars.into_iter().collect()
+}
+
+/// Solves both parts of random noise, of a jumble of the characters of a generated input, and of a
+/// generated input with a few mutations, for each seed. Panics with the seed and input if solving
+/// any of them panics or gives a parse error positioned outside the input; errors are expected
+/// otherwise.
+pub fn assert_no_panic<S: Solution>(generate: fn(&mut Rng, usize) -> String, seeds: Range<u64>, size: usize) {
+  for seed in seeds {
+    let mut rng = Rng::new(seed);
+    let valid = generate(&mut rng, size);
+
+    let mut jumble = valid.chars().collect::<Vec<char>>();
+    rng.shuffle(&mut jumble);
+
+    let inputs = [noise(&mut rng, valid.len()), jumble.into_iter().collect(), mutate(&mut rng, &valid)];
+
+    for input in inputs {
+      let results = match panic::catch_unwind(AssertUnwindSafe(|| solve::<S>(&input, &[1, 2]))) {
+        Ok(results) => results,
+        Err(payload) => panic!("seed {seed}, size {size}: panicked with \"{}\" for input\n{input:?}", panic_message(payload)),
+      };
+
+      if let Some(Err(error)) = results.iter().find(|result| result.as_ref().is_err_and(|error| !points_into(error, &normalize(&input)))) {
+        panic!("seed {seed}, size {size}: \"{error}\" points outside input\n{input:?}");
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_errors_must_point_into_the_input() {
+    assert!(points_into(&Error::parse(2, 3, "x").spanning(2), "abcd\nabcd"));
+    assert!(points_into(&Error::parse(2, 5, "x"), "abcd\nabcd"));
+    assert!(!points_into(&Error::parse(2, 4, "x").spanning(2), "abcd\nabc"));
+    assert!(points_into(&Error::parse(3, 0, "x"), "abcd\nabc"));
+    assert!(points_into(&Error::parse(3, 1, "x"), "abcd\nabc"));
+    assert!(!points_into(&Error::parse(3, 2, "x"), "abcd\nabc"));
+    assert!(!points_into(&Error::parse(4, 0, "x"), "abcd\nabc"));
+  }
+
+  #[test]
+  fn mutations_stay_close_to_the_input() {
+    let input = "467..114..\n...*......\n";
+
+    let mutated = (0..50).map(|seed| mutate(&mut Rng::new(seed), input)).collect::<Vec<String>>();
+
+    assert!(mutated.iter().all(|mutated| mutated.chars().count() <= input.chars().count() + 4));
+    assert!(mutated.iter().filter(|&mutated| mutated != input).count() > 40);
+  }
+}
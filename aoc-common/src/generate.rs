@@ -0,0 +1,148 @@
+use std::{collections::{HashMap, HashSet}, ops::Range};
+
+use crate::{geometry::Point, random::Rng, solution::{Solution, solve}};
+
+/// A grid of `rows` lines of `cols` characters, each picked by `cell`.
+pub fn grid<F>(rng: &mut Rng, rows: usize, cols: usize, mut cell: F) -> String
+where
+  F: FnMut(&mut Rng) -> char,
+{
+  let mut contents = String::with_capacity(rows * (cols + 1));
+
+  for _ in 0..rows {
+    for _ in 0..cols {
+      contents.push(cell(rng));
+    }
+    contents.push('\n');
+  }
+
+  contents
+}
+
+/// Cells of a random tree on a `width` by `height` lattice of nodes, drawn on a grid twice as
+/// fine so that nodes sit on even coordinates and the cells between them are the tree's edges.
+/// The result never encloses a hole and never has two cells meeting only at a corner.
+fn tree_cells(rng: &mut Rng, width: usize, height: usize) -> HashSet<(i64, i64)> {
+  let (width, height) = (width.max(1) as i64, height.max(1) as i64);
+  let target = rng.range((width * height / 2).max(1)..width * height + 1) as usize;
+
+  let start = (rng.range(0..width), rng.range(0..height));
+  let mut nodes = HashSet::from([start]);
+  let mut cells = HashSet::from([(start.0 * 2, start.1 * 2)]);
+  let mut frontier = vec![start];
+
+  while nodes.len() < target && !frontier.is_empty() {
+    let index = rng.below(frontier.len());
+    let (x, y) = frontier[index];
+
+    let neighbors = [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+      .into_iter()
+      .filter(|&(nx, ny)| 0 <= nx && nx < width && 0 <= ny && ny < height && !nodes.contains(&(nx, ny)))
+      .collect::<Vec<Point>>();
+
+    if neighbors.is_empty() {
+      frontier.swap_remove(index);
+      continue;
+    }
+
+    let (nx, ny) = *rng.choose(&neighbors);
+
+    nodes.insert((nx, ny));
+    cells.insert((nx * 2, ny * 2));
+    cells.insert((x + nx, y + ny));
+    frontier.push((nx, ny));
+  }
+
+  cells
+}
+
+/// Corners of a random simple rectilinear polygon with vertices on the lattice `0..=2 * width - 1`
+/// by `0..=2 * height - 1`, listed clockwise with `y` pointing down.
+pub fn rectilinear_loop(rng: &mut Rng, width: usize, height: usize) -> Vec<Point> {
+  let cells = tree_cells(rng, width, height);
+
+  let mut next = HashMap::new();
+  for &(x, y) in cells.iter() {
+    let sides = [
+      ((x, y - 1), (x, y), (x + 1, y)),
+      ((x + 1, y), (x + 1, y), (x + 1, y + 1)),
+      ((x, y + 1), (x + 1, y + 1), (x, y + 1)),
+      ((x - 1, y), (x, y + 1), (x, y)),
+    ];
+
+    for (neighbor, from, to) in sides {
+      if !cells.contains(&neighbor) {
+        next.insert(from, to);
+      }
+    }
+  }
+
+  let start = match next.keys().min() {
+    Some(&start) => start,
+    None => return vec![],
+  };
+
+  let mut points = vec![start];
+  while let Some(&point) = next.get(&points[points.len() - 1]) {
+    if point == start {
+      break;
+    }
+    points.push(point);
+  }
+
+  let count = points.len();
+
+  (0..count)
+    .filter(|&index| {
+      let (previous, point, following) = (points[(index + count - 1) % count], points[index], points[(index + 1) % count]);
+
+      (previous.0 == point.0) != (point.0 == following.0)
+    })
+    .map(|index| points[index])
+    .collect()
+}
+
+/// Solves both parts of the inputs generated from each seed and panics with the seed and input if
+/// either part fails.
+pub fn assert_solvable<S: Solution>(generate: fn(&mut Rng, usize) -> String, seeds: Range<u64>, size: usize) {
+  for seed in seeds {
+    let contents = generate(&mut Rng::new(seed), size);
+
+    for (part, answer) in [1, 2].into_iter().zip(solve::<S>(&contents, &[1, 2])) {
+      if let Err(error) = answer {
+        panic!("seed {seed}, size {size}: part {part} failed with \"{error}\" for input\n{contents}");
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::geometry::{is_self_intersecting, twice_signed_area};
+
+  #[test]
+  fn loops_are_simple_and_rectilinear() {
+    for seed in 0..50 {
+      let vertices = rectilinear_loop(&mut Rng::new(seed), 5, 4);
+
+      assert!(vertices.len() >= 4);
+      assert!(!is_self_intersecting(&vertices));
+      assert!(twice_signed_area(&vertices) != 0);
+
+      for (index, &(x, y)) in vertices.iter().enumerate() {
+        let (nx, ny) = vertices[(index + 1) % vertices.len()];
+
+        assert!((x == nx) != (y == ny));
+        assert!((0..10).contains(&x) && (0..8).contains(&y));
+      }
+    }
+  }
+
+  #[test]
+  fn grids_have_the_requested_shape() {
+    let contents = grid(&mut Rng::new(3), 2, 3, |rng| *rng.choose(&['.', '#']));
+
+    assert_eq!(contents.lines().map(str::len).collect::<Vec<usize>>(), vec![3, 3]);
+  }
+}
@@ -0,0 +1,164 @@
+use crate::math::gcd;
+
+/// A lattice point `(x, y)`.
+pub type Point = (i64, i64);
+
+/// Twice the signed area of the polygon by the shoelace formula; positive when the vertices turn
+/// counter-clockwise with `y` pointing up. The last vertex connects back to the first.
+pub fn twice_signed_area(vertices: &[Point]) -> i128 {
+  let mut sum = 0i128;
+
+  for (index, &(x1, y1)) in vertices.iter().enumerate() {
+    let (x2, y2) = vertices[(index + 1) % vertices.len()];
+
+    sum = sum + x1 as i128 * y2 as i128 - x2 as i128 * y1 as i128;
+  }
+
+  sum
+}
+
+/// Number of lattice points on the edges of the polygon.
+pub fn boundary_points(vertices: &[Point]) -> u64 {
+  let mut count = 0u64;
+
+  for (index, &(x1, y1)) in vertices.iter().enumerate() {
+    let (x2, y2) = vertices[(index + 1) % vertices.len()];
+
+    count += gcd(x1.abs_diff(x2) as usize, y1.abs_diff(y2) as usize) as u64;
+  }
+
+  count
+}
+
+/// Number of lattice points strictly inside the polygon by Pick's theorem, `A = I + B / 2 - 1`.
+/// Only meaningful for simple polygons; see [`is_self_intersecting`].
+pub fn interior_points(vertices: &[Point]) -> u64 {
+  let twice_area = twice_signed_area(vertices).abs();
+
+  if twice_area == 0 {
+    return 0;
+  }
+
+  ((twice_area - boundary_points(vertices) as i128 + 2) / 2).max(0) as u64
+}
+
+/// Number of lattice points inside or on the polygon.
+pub fn lattice_points(vertices: &[Point]) -> u64 {
+  interior_points(vertices) + boundary_points(vertices)
+}
+
+fn difference(to: Point, from: Point) -> (i128, i128) {
+  (to.0 as i128 - from.0 as i128, to.1 as i128 - from.1 as i128)
+}
+
+fn cross(origin: Point, a: Point, b: Point) -> i128 {
+  let (ax, ay) = difference(a, origin);
+  let (bx, by) = difference(b, origin);
+
+  ax * by - ay * bx
+}
+
+/// Whether `point`, known to be collinear with the segment, lies on it.
+fn within_bounds(start: Point, end: Point, point: Point) -> bool {
+  start.0.min(end.0) <= point.0 && point.0 <= start.0.max(end.0) && start.1.min(end.1) <= point.1 && point.1 <= start.1.max(end.1)
+}
+
+fn segments_intersect((a, b): (Point, Point), (c, d): (Point, Point)) -> bool {
+  let [abc, abd, cda, cdb] = [cross(a, b, c), cross(a, b, d), cross(c, d, a), cross(c, d, b)];
+
+  if abc.signum() * abd.signum() < 0 && cda.signum() * cdb.signum() < 0 {
+    return true;
+  }
+
+  (abc == 0 && within_bounds(a, b, c))
+    || (abd == 0 && within_bounds(a, b, d))
+    || (cda == 0 && within_bounds(c, d, a))
+    || (cdb == 0 && within_bounds(c, d, b))
+}
+
+/// Whether the edge `b -> c` doubles back over the edge `a -> b`.
+fn folds_back(a: Point, b: Point, c: Point) -> bool {
+  let (ux, uy) = difference(b, a);
+  let (vx, vy) = difference(c, b);
+
+  cross(a, b, c) == 0 && ux * vx + uy * vy < 0
+}
+
+/// Whether any two edges touch other than neighbouring edges at their shared vertex. Repeated
+/// consecutive vertices are ignored. Compares every pair of edges, so takes quadratic time.
+pub fn is_self_intersecting(vertices: &[Point]) -> bool {
+  let mut points = vertices.to_vec();
+  points.dedup();
+
+  while points.len() > 1 && points.first() == points.last() {
+    points.pop();
+  }
+
+  let count = points.len();
+  if count < 2 {
+    return false;
+  }
+
+  let edge = |index: usize| (points[index], points[(index + 1) % count]);
+
+  for i in 0..count {
+    for j in (i + 1)..count {
+      let touches = if j == i + 1 {
+        folds_back(points[i], points[j], points[(j + 1) % count])
+      } else if i == 0 && j == count - 1 {
+        folds_back(points[j], points[0], points[1])
+      } else {
+        segments_intersect(edge(i), edge(j))
+      };
+
+      if touches {
+        return true;
+      }
+    }
+  }
+
+  false
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const SQUARE: [Point; 4] = [(0, 0), (4, 0), (4, 4), (0, 4)];
+
+  #[test]
+  fn shoelace_sign_follows_orientation() {
+    let mut reversed = SQUARE;
+    reversed.reverse();
+
+    assert_eq!(twice_signed_area(&SQUARE), 32);
+    assert_eq!(twice_signed_area(&reversed), -32);
+  }
+
+  #[test]
+  fn picks_theorem_counts_points() {
+    assert_eq!(boundary_points(&SQUARE), 16);
+    assert_eq!(interior_points(&SQUARE), 9);
+    assert_eq!(lattice_points(&SQUARE), 25);
+
+    let triangle = [(0, 0), (4, 0), (0, 2)];
+    assert_eq!(boundary_points(&triangle), 8);
+    assert_eq!(interior_points(&triangle), 1);
+  }
+
+  #[test]
+  fn degenerate_polygons_have_no_interior() {
+    assert_eq!(interior_points(&[]), 0);
+    assert_eq!(interior_points(&[(1, 1)]), 0);
+  }
+
+  #[test]
+  fn detects_crossings_and_folds() {
+    assert!(!is_self_intersecting(&SQUARE));
+    assert!(!is_self_intersecting(&[(0, 0), (2, 0), (2, 0), (2, 2), (0, 0)]));
+    assert!(is_self_intersecting(&[(0, 0), (2, 2), (2, 0), (0, 2)]));
+    assert!(is_self_intersecting(&[(0, 0), (4, 0), (4, 2), (2, 0), (2, -2), (0, -2)]));
+    assert!(is_self_intersecting(&[(0, 0), (3, 0), (1, 0), (1, 2)]));
+    assert!(is_self_intersecting(&[(0, 0), (2, 0)]));
+  }
+}
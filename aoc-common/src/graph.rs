@@ -0,0 +1,421 @@
+use std::{cmp::Reverse, collections::{BinaryHeap, HashMap, VecDeque}, hash::Hash, ops::Add};
+
+/// Nodes reachable from `node` in a single step.
+pub trait Adjacency<N> {
+  fn neighbors(&self, node: &N) -> Vec<N>;
+}
+
+impl<N, F, I> Adjacency<N> for F
+where
+  F: Fn(&N) -> I,
+  I: IntoIterator<Item = N>,
+{
+  fn neighbors(&self, node: &N) -> Vec<N> {
+    self(node).into_iter().collect()
+  }
+}
+
+impl<N: Clone + Eq + Hash> Adjacency<N> for HashMap<N, Vec<N>> {
+  fn neighbors(&self, node: &N) -> Vec<N> {
+    self.get(node).cloned().unwrap_or_default()
+  }
+}
+
+/// `(neighbor, cost)` for every edge leaving `node`.
+pub trait WeightedAdjacency<N, C> {
+  fn edges(&self, node: &N) -> Vec<(N, C)>;
+}
+
+impl<N, C, F, I> WeightedAdjacency<N, C> for F
+where
+  F: Fn(&N) -> I,
+  I: IntoIterator<Item = (N, C)>,
+{
+  fn edges(&self, node: &N) -> Vec<(N, C)> {
+    self(node).into_iter().collect()
+  }
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> WeightedAdjacency<N, C> for HashMap<N, Vec<(N, C)>> {
+  fn edges(&self, node: &N) -> Vec<(N, C)> {
+    self.get(node).cloned().unwrap_or_default()
+  }
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> WeightedAdjacency<N, C> for HashMap<N, HashMap<N, C>> {
+  fn edges(&self, node: &N) -> Vec<(N, C)> {
+    match self.get(node) {
+      Some(edges) => edges.iter().map(|(next, &cost)| (next.clone(), cost)).collect(),
+      None => Vec::new(),
+    }
+  }
+}
+
+/// Assigns every distinct node a dense index so the algorithms can work on plain vectors.
+struct Interner<N> {
+  nodes: Vec<N>,
+  indices: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash> Interner<N> {
+  fn new() -> Interner<N> {
+    Interner{ nodes: Vec::new(), indices: HashMap::new() }
+  }
+
+  /// The node's index and whether it was seen for the first time.
+  fn intern(&mut self, node: N) -> (usize, bool) {
+    if let Some(&index) = self.indices.get(&node) {
+      return (index, false);
+    }
+
+    let index = self.nodes.len();
+    self.indices.insert(node.clone(), index);
+    self.nodes.push(node);
+
+    (index, true)
+  }
+}
+
+/// Step counts from `start` to every node reachable in at most `max_distance` steps.
+pub fn bfs_distances<N, G>(graph: &G, start: N, max_distance: usize) -> HashMap<N, usize>
+where
+  N: Clone + Eq + Hash,
+  G: Adjacency<N>,
+{
+  let mut distances = HashMap::new();
+  distances.insert(start.clone(), 0);
+
+  let mut queue = VecDeque::new();
+  queue.push_back((start, 0));
+
+  while let Some((node, distance)) = queue.pop_front() {
+    if distance == max_distance {
+      continue;
+    }
+
+    for next in graph.neighbors(&node) {
+      if !distances.contains_key(&next) {
+        distances.insert(next.clone(), distance + 1);
+        queue.push_back((next, distance + 1));
+      }
+    }
+  }
+
+  distances
+}
+
+/// Cheapest path from any of `starts` to the first node satisfying `is_goal`, as its cost and
+/// the nodes along it. `heuristic` must never overestimate the remaining cost.
+pub fn astar<N, C, G, P, H>(graph: &G, starts: impl IntoIterator<Item = N>, mut is_goal: P, heuristic: H) -> Option<(C, Vec<N>)>
+where
+  N: Clone + Eq + Hash,
+  C: Copy + Ord + Default + Add<Output = C>,
+  G: WeightedAdjacency<N, C>,
+  P: FnMut(&N) -> bool,
+  H: Fn(&N) -> C,
+{
+  let mut interner = Interner::new();
+  let mut costs: Vec<C> = Vec::new();
+  let mut parents: Vec<Option<usize>> = Vec::new();
+  let mut queue = BinaryHeap::new();
+
+  for start in starts {
+    let estimate = heuristic(&start);
+    let (index, is_new) = interner.intern(start);
+
+    if is_new {
+      costs.push(C::default());
+      parents.push(None);
+      queue.push(Reverse((estimate, C::default(), index)));
+    }
+  }
+
+  while let Some(Reverse((_, cost, index))) = queue.pop() {
+    if cost > costs[index] {
+      continue;
+    }
+
+    if is_goal(&interner.nodes[index]) {
+      let mut path = vec![interner.nodes[index].clone()];
+      let mut current = index;
+
+      while let Some(parent) = parents[current] {
+        path.push(interner.nodes[parent].clone());
+        current = parent;
+      }
+
+      path.reverse();
+
+      return Some((cost, path));
+    }
+
+    for (next, weight) in graph.edges(&interner.nodes[index]) {
+      let next_cost = cost + weight;
+      let estimate = next_cost + heuristic(&next);
+      let (next_index, is_new) = interner.intern(next);
+
+      if is_new {
+        costs.push(next_cost);
+        parents.push(Some(index));
+      } else if next_cost < costs[next_index] {
+        costs[next_index] = next_cost;
+        parents[next_index] = Some(index);
+      } else {
+        continue;
+      }
+
+      queue.push(Reverse((estimate, next_cost, next_index)));
+    }
+  }
+
+  None
+}
+
+/// [`astar`] without a heuristic.
+pub fn dijkstra<N, C, G, P>(graph: &G, starts: impl IntoIterator<Item = N>, is_goal: P) -> Option<(C, Vec<N>)>
+where
+  N: Clone + Eq + Hash,
+  C: Copy + Ord + Default + Add<Output = C>,
+  G: WeightedAdjacency<N, C>,
+  P: FnMut(&N) -> bool,
+{
+  astar(graph, starts, is_goal, |_| C::default())
+}
+
+/// Tarjan's strongly connected components of everything reachable from `nodes`, in reverse
+/// topological order: no component has an edge to one listed after it.
+pub fn strongly_connected_components<N, G>(graph: &G, nodes: impl IntoIterator<Item = N>) -> Vec<Vec<N>>
+where
+  N: Clone + Eq + Hash,
+  G: Adjacency<N>,
+{
+  let mut interner = Interner::new();
+  let mut adjacency: Vec<Vec<usize>> = Vec::new();
+  let mut order: Vec<Option<usize>> = Vec::new();
+  let mut lowlinks: Vec<usize> = Vec::new();
+  let mut on_stack: Vec<bool> = Vec::new();
+  let mut stack = Vec::new();
+  let mut components = Vec::new();
+  let mut counter = 0;
+
+  let roots = nodes
+    .into_iter()
+    .map(|node| interner.intern(node).0)
+    .collect::<Vec<usize>>();
+
+  for root in roots {
+    if order.get(root).copied().flatten().is_some() {
+      continue;
+    }
+
+    let mut call_stack = vec![(root, 0)];
+
+    while let Some(&(node, edge)) = call_stack.last() {
+      if node >= adjacency.len() || order[node].is_none() {
+        let neighbors = graph
+          .neighbors(&interner.nodes[node])
+          .into_iter()
+          .map(|next| interner.intern(next).0)
+          .collect::<Vec<usize>>();
+
+        let size = interner.nodes.len();
+        adjacency.resize(size, Vec::new());
+        order.resize(size, None);
+        lowlinks.resize(size, 0);
+        on_stack.resize(size, false);
+
+        adjacency[node] = neighbors;
+        order[node] = Some(counter);
+        lowlinks[node] = counter;
+        on_stack[node] = true;
+        stack.push(node);
+        counter += 1;
+      }
+
+      if let Some(&next) = adjacency[node].get(edge) {
+        if let Some(frame) = call_stack.last_mut() {
+          frame.1 = edge + 1;
+        }
+
+        match order[next] {
+          None => call_stack.push((next, 0)),
+          Some(next_order) if on_stack[next] => lowlinks[node] = lowlinks[node].min(next_order),
+          Some(_) => {},
+        }
+
+        continue;
+      }
+
+      call_stack.pop();
+
+      if let Some(&(parent, _)) = call_stack.last() {
+        lowlinks[parent] = lowlinks[parent].min(lowlinks[node]);
+      }
+
+      if Some(lowlinks[node]) == order[node] {
+        let mut component = Vec::new();
+
+        while let Some(member) = stack.pop() {
+          on_stack[member] = false;
+          component.push(interner.nodes[member].clone());
+
+          if member == node {
+            break;
+          }
+        }
+
+        components.push(component);
+      }
+    }
+  }
+
+  components
+}
+
+/// Every node reachable from `roots`, ordered so that each node comes before all of its
+/// neighbors, or `None` if a cycle is reachable.
+pub fn topological_sort<N, G>(graph: &G, roots: impl IntoIterator<Item = N>) -> Option<Vec<N>>
+where
+  N: Clone + Eq + Hash,
+  G: Adjacency<N>,
+{
+  let components = strongly_connected_components(graph, roots);
+
+  let mut sorted = Vec::with_capacity(components.len());
+  for mut component in components.into_iter().rev() {
+    let node = match component.pop() {
+      Some(node) if component.is_empty() => node,
+      _ => return None,
+    };
+
+    if graph.neighbors(&node).contains(&node) {
+      return None;
+    }
+
+    sorted.push(node);
+  }
+
+  Some(sorted)
+}
+
+/// Longest distance from `start` to every node reachable from it, or `None` if a cycle is
+/// reachable.
+pub fn dag_longest_paths<N, C, G>(graph: &G, start: N) -> Option<HashMap<N, C>>
+where
+  N: Clone + Eq + Hash,
+  C: Copy + Ord + Default + Add<Output = C>,
+  G: WeightedAdjacency<N, C>,
+{
+  let unweighted = |node: &N| graph.edges(node).into_iter().map(|(next, _)| next);
+
+  let order = topological_sort(&unweighted, [start.clone()])?;
+
+  let mut distances = HashMap::new();
+  distances.insert(start, C::default());
+
+  for node in order {
+    let distance = match distances.get(&node) {
+      Some(&distance) => distance,
+      None => continue,
+    };
+
+    for (next, weight) in graph.edges(&node) {
+      let candidate = distance + weight;
+
+      match distances.get(&next) {
+        Some(&current) if current >= candidate => {},
+        _ => {
+          distances.insert(next, candidate);
+        },
+      }
+    }
+  }
+
+  Some(distances)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn diamond() -> HashMap<char, Vec<(char, u32)>> {
+    HashMap::from([
+      ('a', vec![('b', 1), ('c', 4)]),
+      ('b', vec![('c', 1), ('d', 6)]),
+      ('c', vec![('d', 1)]),
+    ])
+  }
+
+  #[test]
+  fn bfs_respects_max_distance() {
+    let line = |&node: &u32| if node < 10 { vec![node + 1] } else { vec![] };
+
+    assert_eq!(bfs_distances(&line, 0, 3), HashMap::from([(0, 0), (1, 1), (2, 2), (3, 3)]));
+    assert_eq!(bfs_distances(&line, 0, usize::MAX).len(), 11);
+  }
+
+  #[test]
+  fn dijkstra_returns_cost_and_path() {
+    assert_eq!(dijkstra(&diamond(), ['a'], |&node| node == 'd'), Some((3, vec!['a', 'b', 'c', 'd'])));
+    assert_eq!(dijkstra(&diamond(), ['d'], |&node| node == 'a'), None);
+  }
+
+  #[test]
+  fn astar_matches_dijkstra_on_a_grid() {
+    let grid = |&(row, col): &(i32, i32)| {
+      [(row + 1, col), (row, col + 1)]
+        .into_iter()
+        .filter(|&(row, col)| row <= 5 && col <= 5)
+        .map(|next| (next, 1u32))
+    };
+    let heuristic = |&(row, col): &(i32, i32)| (5 - row + 5 - col) as u32;
+
+    let (cost, path) = astar(&grid, [(0, 0)], |&node| node == (5, 5), heuristic).unwrap();
+
+    assert_eq!(cost, 10);
+    assert_eq!(path.len(), 11);
+    assert_eq!(dijkstra(&grid, [(0, 0)], |&node| node == (5, 5)).map(|(cost, _)| cost), Some(10));
+  }
+
+  #[test]
+  fn scc_groups_cycles() {
+    let graph = HashMap::from([
+      (1, vec![2]),
+      (2, vec![3]),
+      (3, vec![1, 4]),
+      (4, vec![5]),
+      (5, vec![4]),
+    ]);
+
+    let components = strongly_connected_components(&graph, [1])
+      .into_iter()
+      .map(|mut component| {
+        component.sort();
+        component
+      })
+      .collect::<Vec<Vec<i32>>>();
+
+    assert_eq!(components, vec![vec![4, 5], vec![1, 2, 3]]);
+  }
+
+  #[test]
+  fn topological_sort_orders_edges() {
+    let unweighted = |node: &char| diamond().edges(node).into_iter().map(|(next, _)| next);
+
+    assert_eq!(topological_sort(&unweighted, ['a']), Some(vec!['a', 'b', 'c', 'd']));
+  }
+
+  #[test]
+  fn topological_sort_rejects_cycles() {
+    assert_eq!(topological_sort(&HashMap::from([(1, vec![2]), (2, vec![1])]), [1]), None);
+    assert_eq!(topological_sort(&HashMap::from([(1, vec![1])]), [1]), None);
+  }
+
+  #[test]
+  fn longest_paths_in_dag() {
+    let distances = dag_longest_paths(&diamond(), 'a').unwrap();
+
+    assert_eq!(distances[&'d'], 7);
+    assert_eq!(distances[&'c'], 4);
+  }
+}
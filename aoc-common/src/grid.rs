@@ -0,0 +1,317 @@
+use std::{fmt, ops::{Index, IndexMut}};
+
+use crate::error::Error;
+
+const OFFSETS4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+const OFFSETS8: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1)];
+
+/// A rectangular grid stored flat in row-major order and indexed by `(row, col)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+  cells: Vec<T>,
+  rows: usize,
+  cols: usize,
+}
+
+impl<T> Grid<T> {
+  pub fn new(rows: usize, cols: usize, value: T) -> Grid<T> where T: Clone {
+    Grid{ cells: vec![value; rows * cols], rows, cols }
+  }
+
+  /// Builds a grid by calling `f` with every position in row-major order.
+  pub fn from_fn<F>(rows: usize, cols: usize, mut f: F) -> Grid<T>
+  where
+    F: FnMut((usize, usize)) -> T,
+  {
+    let cells = (0..(rows * cols)).map(|index| f((index / cols, index % cols))).collect();
+
+    Grid{ cells, rows, cols }
+  }
+
+  /// Builds a grid from nested rows; every row must have the same length.
+  pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, Error> {
+    let cols = rows.first().map(|row| row.len()).unwrap_or(0);
+    let row_count = rows.len();
+
+    let mut cells = Vec::with_capacity(row_count * cols);
+    for (index, row) in rows.into_iter().enumerate() {
+      if row.len() != cols {
+        return Err(Error::parse(index + 1, 0, format!("expected {cols} columns, found {}", row.len())));
+      }
+
+      cells.extend(row);
+    }
+
+    Ok(Grid{ cells, rows: row_count, cols })
+  }
+
+  /// Parses one cell per character, one row per line.
+  pub fn parse_with<F>(contents: &str, mut parse_cell: F) -> Result<Grid<T>, Error>
+  where
+    F: FnMut(char) -> Option<T>,
+  {
+    let rows = contents
+      .lines()
+      .enumerate()
+      .map(|(row, line)| {
+        line
+          .char_indices()
+          .map(|(offset, char)| {
+            match parse_cell(char) {
+              Some(value) => Ok(value),
+              None => Err(Error::parse(row + 1, offset + 1, format!("unexpected character \"{char}\"")).spanning(char.len_utf8())),
+            }
+          })
+          .collect::<Result<Vec<T>, Error>>()
+      })
+      .collect::<Result<Vec<Vec<T>>, Error>>();
+
+    match rows {
+      Ok(rows) => Grid::from_rows(rows),
+      Err(error) => Err(error),
+    }
+  }
+
+  pub fn rows(&self) -> usize {
+    self.rows
+  }
+
+  pub fn cols(&self) -> usize {
+    self.cols
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.cells.is_empty()
+  }
+
+  pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+    if row < self.rows && col < self.cols {
+      Some(&self.cells[row * self.cols + col])
+    } else {
+      None
+    }
+  }
+
+  pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+    if row < self.rows && col < self.cols {
+      Some(&mut self.cells[row * self.cols + col])
+    } else {
+      None
+    }
+  }
+
+  /// Lookup on the grid tiled infinitely in every direction.
+  pub fn get_wrapped(&self, row: isize, col: isize) -> &T {
+    let (row, col) = self.wrap(row, col);
+
+    &self.cells[row * self.cols + col]
+  }
+
+  /// Maps a position on the infinitely tiled grid back onto this grid.
+  pub fn wrap(&self, row: isize, col: isize) -> (usize, usize) {
+    (row.rem_euclid(self.rows as isize) as usize, col.rem_euclid(self.cols as isize) as usize)
+  }
+
+  /// `position` moved by `offset`, or `None` if that leaves the grid.
+  pub fn offset(&self, position: (usize, usize), offset: (isize, isize)) -> Option<(usize, usize)> {
+    let row = position.0.checked_add_signed(offset.0)?;
+    let col = position.1.checked_add_signed(offset.1)?;
+
+    if row < self.rows && col < self.cols {
+      Some((row, col))
+    } else {
+      None
+    }
+  }
+
+  /// In-bounds orthogonal neighbours, clockwise from up.
+  pub fn neighbors4(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+    OFFSETS4.iter().filter_map(move |&offset| self.offset(position, offset))
+  }
+
+  /// In-bounds orthogonal and diagonal neighbours, clockwise from up-left.
+  pub fn neighbors8(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+    OFFSETS8.iter().filter_map(move |&offset| self.offset(position, offset))
+  }
+
+  pub fn row(&self, row: usize) -> &[T] {
+    &self.cells[(row * self.cols)..((row + 1) * self.cols)]
+  }
+
+  pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+    (0..self.rows).map(|row| self.row(row))
+  }
+
+  /// Every position in row-major order.
+  pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+    let cols = self.cols;
+
+    (0..(self.rows * cols)).map(move |index| (index / cols, index % cols))
+  }
+
+  pub fn find<P>(&self, predicate: P) -> Option<(usize, usize)>
+  where
+    P: FnMut(&T) -> bool,
+  {
+    self.cells
+      .iter()
+      .position(predicate)
+      .map(|index| (index / self.cols, index % self.cols))
+  }
+
+  pub fn map<U, F>(&self, f: F) -> Grid<U>
+  where
+    F: FnMut(&T) -> U,
+  {
+    Grid{ cells: self.cells.iter().map(f).collect(), rows: self.rows, cols: self.cols }
+  }
+
+  pub fn transpose(&self) -> Grid<T> where T: Clone {
+    let cells = (0..self.cols)
+      .flat_map(|col| (0..self.rows).map(move |row| (row, col)))
+      .map(|(row, col)| self[(row, col)].clone())
+      .collect();
+
+    Grid{ cells, rows: self.cols, cols: self.rows }
+  }
+
+  pub fn rotate_clockwise(&self) -> Grid<T> where T: Clone {
+    let cells = (0..self.cols)
+      .flat_map(|col| (0..self.rows).rev().map(move |row| (row, col)))
+      .map(|(row, col)| self[(row, col)].clone())
+      .collect();
+
+    Grid{ cells, rows: self.cols, cols: self.rows }
+  }
+
+  pub fn rotate_counterclockwise(&self) -> Grid<T> where T: Clone {
+    let cells = (0..self.cols)
+      .rev()
+      .flat_map(|col| (0..self.rows).map(move |row| (row, col)))
+      .map(|(row, col)| self[(row, col)].clone())
+      .collect();
+
+    Grid{ cells, rows: self.cols, cols: self.rows }
+  }
+}
+
+impl Grid<char> {
+  pub fn parse(contents: &str) -> Result<Grid<char>, Error> {
+    Grid::parse_with(contents, Some)
+  }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+  type Output = T;
+
+  fn index(&self, (row, col): (usize, usize)) -> &T {
+    assert!(row < self.rows && col < self.cols, "position ({row}, {col}) is outside of a {}x{} grid", self.rows, self.cols);
+
+    &self.cells[row * self.cols + col]
+  }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+  fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+    assert!(row < self.rows && col < self.cols, "position ({row}, {col}) is outside of a {}x{} grid", self.rows, self.cols);
+
+    &mut self.cells[row * self.cols + col]
+  }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for (index, row) in self.iter_rows().enumerate() {
+      if index > 0 {
+        writeln!(f)?;
+      }
+
+      for cell in row {
+        write!(f, "{cell}")?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EXAMPLE: &str = "abc\ndef";
+
+  #[test]
+  fn parses_rows_and_columns() {
+    let grid = Grid::parse(EXAMPLE).unwrap();
+
+    assert_eq!((grid.rows(), grid.cols()), (2, 3));
+    assert_eq!(grid[(1, 2)], 'f');
+    assert_eq!(grid.get(2, 0), None);
+    assert_eq!(grid.get(0, 3), None);
+  }
+
+  #[test]
+  fn rejects_ragged_rows() {
+    assert_eq!(Grid::parse("abc\nde").err(), Some(Error::parse(2, 0, "expected 3 columns, found 2")));
+  }
+
+  #[test]
+  fn parse_with_reports_position() {
+    let result = Grid::parse_with("12\n3x", |char| char.to_digit(10));
+    assert_eq!(result.err(), Some(Error::parse(2, 2, "unexpected character \"x\"").spanning(1)));
+
+    let result = Grid::parse_with("é9x", |char| char.to_digit(10));
+    assert_eq!(result.err(), Some(Error::parse(1, 1, "unexpected character \"é\"").spanning(2)));
+  }
+
+  #[test]
+  fn neighbors_stay_in_bounds() {
+    let grid = Grid::parse(EXAMPLE).unwrap();
+
+    assert_eq!(grid.neighbors4((0, 0)).collect::<Vec<_>>(), vec![(0, 1), (1, 0)]);
+    assert_eq!(grid.neighbors4((1, 1)).collect::<Vec<_>>(), vec![(0, 1), (1, 2), (1, 0)]);
+    assert_eq!(grid.neighbors8((0, 2)).collect::<Vec<_>>(), vec![(1, 2), (1, 1), (0, 1)]);
+    assert_eq!(grid.neighbors8((1, 1)).count(), 5);
+  }
+
+  #[test]
+  fn wrapped_lookup_tiles_the_grid() {
+    let grid = Grid::parse(EXAMPLE).unwrap();
+
+    assert_eq!(*grid.get_wrapped(-1, -1), 'f');
+    assert_eq!(*grid.get_wrapped(2, 4), 'b');
+    assert_eq!(grid.wrap(-3, 7), (1, 1));
+  }
+
+  #[test]
+  fn transpose_and_rotate() {
+    let grid = Grid::parse(EXAMPLE).unwrap();
+
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+    assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+    assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+    assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+  }
+
+  #[test]
+  fn display_round_trips() {
+    assert_eq!(Grid::parse(EXAMPLE).unwrap().to_string(), EXAMPLE);
+  }
+
+  #[test]
+  fn find_returns_first_match() {
+    let grid = Grid::parse(EXAMPLE).unwrap();
+
+    assert_eq!(grid.find(|&char| char == 'e'), Some((1, 1)));
+    assert_eq!(grid.find(|&char| char == 'z'), None);
+  }
+
+  #[test]
+  fn from_fn_visits_row_major() {
+    let grid = Grid::from_fn(2, 2, |(row, col)| row * 10 + col);
+
+    assert_eq!(grid.to_string(), "01\n1011");
+  }
+}
@@ -0,0 +1,109 @@
+use std::{borrow::Cow, fs, io::{self, Read}};
+
+use crate::error::Error;
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+  Stdin,
+  File(String),
+}
+
+impl InputSource {
+  /// `-` selects stdin, anything else is a file path.
+  pub fn from_arg(arg: &str) -> InputSource {
+    match arg {
+      "-" => InputSource::Stdin,
+      path => InputSource::File(path.to_string()),
+    }
+  }
+
+  /// The source named by `arg`, or the day's default file when no argument was given.
+  pub fn from_arg_or_default(arg: Option<&str>, default_path: &str) -> InputSource {
+    match arg {
+      Some(arg) => InputSource::from_arg(arg),
+      None => InputSource::File(default_path.to_string()),
+    }
+  }
+
+  pub fn read(&self) -> Result<String, Error> {
+    match self {
+      InputSource::Stdin => {
+        let mut contents = String::new();
+
+        match io::stdin().read_to_string(&mut contents) {
+          Ok(_) => Ok(contents),
+          Err(error) => Err(Error::Io(format!("unable to read input from stdin: {error}"))),
+        }
+      },
+      InputSource::File(path) => {
+        match fs::read_to_string(path) {
+          Ok(contents) => Ok(contents),
+          Err(error) => Err(Error::Io(format!("unable to read input file \"{path}\": {error}"))),
+        }
+      },
+    }
+  }
+}
+
+/// Strips a leading BOM, converts CRLF line endings to LF and trims trailing whitespace,
+/// so parsers only ever see `\n`-separated lines without a final newline.
+pub fn normalize(contents: &str) -> Cow<'_, str> {
+  let contents = contents.strip_prefix('\u{feff}').unwrap_or(contents);
+  let contents = contents.trim_end();
+
+  if contents.contains("\r\n") {
+    Cow::Owned(contents.replace("\r\n", "\n"))
+  } else {
+    Cow::Borrowed(contents)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn dash_selects_stdin() {
+    assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+    assert_eq!(InputSource::from_arg("input.txt"), InputSource::File(String::from("input.txt")));
+  }
+
+  #[test]
+  fn falls_back_to_default() {
+    assert_eq!(InputSource::from_arg_or_default(None, "day-01/input.txt"), InputSource::File(String::from("day-01/input.txt")));
+    assert_eq!(InputSource::from_arg_or_default(Some("-"), "day-01/input.txt"), InputSource::Stdin);
+  }
+
+  #[test]
+  fn missing_file_is_an_io_error() {
+    let result = InputSource::File(String::from("does-not-exist/input.txt")).read();
+
+    assert!(matches!(result, Err(Error::Io(_))));
+  }
+
+  #[test]
+  fn normalize_strips_bom() {
+    assert_eq!(normalize("\u{feff}abc\ndef"), "abc\ndef");
+  }
+
+  #[test]
+  fn normalize_converts_crlf() {
+    assert_eq!(normalize("abc\r\n\r\ndef\r\n"), "abc\n\ndef");
+  }
+
+  #[test]
+  fn normalize_trims_trailing_whitespace() {
+    assert_eq!(normalize("abc\ndef\n\n  \t"), "abc\ndef");
+  }
+
+  #[test]
+  fn normalize_keeps_clean_input_borrowed() {
+    assert!(matches!(normalize("abc\ndef"), Cow::Borrowed("abc\ndef")));
+  }
+
+  #[test]
+  fn normalize_handles_all_variants_together() {
+    assert_eq!(normalize("\u{feff}rn=1,cm-\r\n"), "rn=1,cm-");
+  }
+}
@@ -0,0 +1,258 @@
+/// Half-open range of integers `start..end`; empty when `start >= end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+  pub start: i64,
+  pub end: i64,
+}
+
+impl Interval {
+  pub fn new(start: i64, end: i64) -> Interval {
+    Interval{ start, end }
+  }
+
+  /// `length` integers starting at `start`, saturating at `i64::MAX`.
+  pub fn with_length(start: i64, length: u64) -> Interval {
+    let end = i64::try_from(length).map_or(i64::MAX, |length| start.saturating_add(length));
+
+    Interval{ start, end }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.start >= self.end
+  }
+
+  /// Number of integers in the interval.
+  pub fn len(&self) -> u64 {
+    if self.is_empty() {
+      0
+    } else {
+      self.end.abs_diff(self.start)
+    }
+  }
+
+  pub fn contains(&self, value: i64) -> bool {
+    self.start <= value && value < self.end
+  }
+
+  /// The common part, or `None` if the intervals do not overlap.
+  pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+    let result = Interval{ start: self.start.max(other.start), end: self.end.min(other.end) };
+
+    if result.is_empty() { None } else { Some(result) }
+  }
+
+  /// The non-empty parts of `self` outside `other`: nothing, one piece or the two pieces on either side.
+  pub fn subtract(&self, other: &Interval) -> Vec<Interval> {
+    if self.is_empty() {
+      return vec![];
+    }
+
+    if self.intersection(other).is_none() {
+      return vec![*self];
+    }
+
+    [Interval{ start: self.start, end: other.start }, Interval{ start: other.end, end: self.end }]
+      .into_iter()
+      .filter(|piece| !piece.is_empty())
+      .collect()
+  }
+
+  /// One interval if the two overlap or touch, otherwise both in ascending order. Empty inputs are dropped.
+  pub fn union(&self, other: &Interval) -> Vec<Interval> {
+    union_all([*self, *other])
+  }
+
+  /// The parts below `value` and from `value` on.
+  pub fn split_at(&self, value: i64) -> (Option<Interval>, Option<Interval>) {
+    let below = Interval{ start: self.start, end: self.end.min(value) };
+    let above = Interval{ start: self.start.max(value), end: self.end };
+
+    (Some(below).filter(|below| !below.is_empty()), Some(above).filter(|above| !above.is_empty()))
+  }
+
+  /// Moves both ends by `offset`, saturating at the bounds of `i64`.
+  pub fn shift(&self, offset: i64) -> Interval {
+    Interval{ start: self.start.saturating_add(offset), end: self.end.saturating_add(offset) }
+  }
+}
+
+/// Sorted, disjoint and non-adjacent intervals covering the same integers as `intervals`.
+pub fn union_all(intervals: impl IntoIterator<Item = Interval>) -> Vec<Interval> {
+  let mut intervals = intervals.into_iter().filter(|interval| !interval.is_empty()).collect::<Vec<_>>();
+  intervals.sort_by_key(|interval| interval.start);
+
+  let mut result: Vec<Interval> = Vec::with_capacity(intervals.len());
+
+  for interval in intervals {
+    match result.last_mut() {
+      Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+      _ => result.push(interval),
+    }
+  }
+
+  result
+}
+
+/// Axis-aligned box in `N` dimensions: every point whose coordinates all fall in the matching interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hyperrectangle<const N: usize> {
+  pub axes: [Interval; N],
+}
+
+impl<const N: usize> Hyperrectangle<N> {
+  pub fn new(axes: [Interval; N]) -> Hyperrectangle<N> {
+    Hyperrectangle{ axes }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.axes.iter().any(|axis| axis.is_empty())
+  }
+
+  /// Number of points in the box, saturating at `u128::MAX`.
+  pub fn volume(&self) -> u128 {
+    self.axes.iter().fold(1u128, |volume, axis| volume.saturating_mul(axis.len() as u128))
+  }
+
+  pub fn contains(&self, point: [i64; N]) -> bool {
+    self.axes.iter().zip(point).all(|(axis, value)| axis.contains(value))
+  }
+
+  pub fn intersection(&self, other: &Hyperrectangle<N>) -> Option<Hyperrectangle<N>> {
+    let mut axes = self.axes;
+
+    for (axis, other_axis) in axes.iter_mut().zip(&other.axes) {
+      *axis = axis.intersection(other_axis)?;
+    }
+
+    Some(Hyperrectangle{ axes })
+  }
+
+  /// Disjoint boxes covering the points of `self` outside `other`, at most two per dimension.
+  pub fn subtract(&self, other: &Hyperrectangle<N>) -> Vec<Hyperrectangle<N>> {
+    if self.is_empty() {
+      return vec![];
+    }
+
+    let common = match self.intersection(other) {
+      Some(common) => common,
+      None => return vec![*self],
+    };
+
+    let mut pieces = vec![];
+    let mut remainder = *self;
+
+    for dimension in 0..N {
+      for axis in remainder.axes[dimension].subtract(&common.axes[dimension]) {
+        let mut piece = remainder;
+        piece.axes[dimension] = axis;
+        pieces.push(piece);
+      }
+
+      remainder.axes[dimension] = common.axes[dimension];
+    }
+
+    pieces
+  }
+
+  /// Disjoint boxes covering the points of both: `self` followed by what `other` adds to it.
+  pub fn union(&self, other: &Hyperrectangle<N>) -> Vec<Hyperrectangle<N>> {
+    let mut pieces = if self.is_empty() { vec![] } else { vec![*self] };
+    pieces.extend(other.subtract(self));
+
+    pieces
+  }
+
+  /// The parts whose coordinate in `dimension` is below `value` and from `value` on.
+  pub fn split_at(&self, dimension: usize, value: i64) -> (Option<Hyperrectangle<N>>, Option<Hyperrectangle<N>>) {
+    if self.is_empty() {
+      return (None, None);
+    }
+
+    let (below, above) = self.axes[dimension].split_at(value);
+
+    let with_axis = |axis: Interval| {
+      let mut result = *self;
+      result.axes[dimension] = axis;
+      result
+    };
+
+    (below.map(with_axis), above.map(with_axis))
+  }
+
+  pub fn shift(&self, offsets: [i64; N]) -> Hyperrectangle<N> {
+    let mut axes = self.axes;
+
+    for (axis, offset) in axes.iter_mut().zip(offsets) {
+      *axis = axis.shift(offset);
+    }
+
+    Hyperrectangle{ axes }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn interval_length_and_membership() {
+    let interval = Interval::with_length(10, 5);
+
+    assert_eq!(interval, Interval::new(10, 15));
+    assert_eq!(interval.len(), 5);
+    assert!(interval.contains(14) && !interval.contains(15));
+    assert_eq!(Interval::new(3, 1).len(), 0);
+  }
+
+  #[test]
+  fn interval_set_operations() {
+    let interval = Interval::new(0, 10);
+
+    assert_eq!(interval.intersection(&Interval::new(5, 20)), Some(Interval::new(5, 10)));
+    assert_eq!(interval.intersection(&Interval::new(10, 20)), None);
+    assert_eq!(interval.subtract(&Interval::new(3, 5)), vec![Interval::new(0, 3), Interval::new(5, 10)]);
+    assert_eq!(interval.subtract(&Interval::new(-5, 20)), vec![]);
+    assert_eq!(interval.union(&Interval::new(10, 12)), vec![Interval::new(0, 12)]);
+    assert_eq!(interval.union(&Interval::new(11, 12)), vec![interval, Interval::new(11, 12)]);
+  }
+
+  #[test]
+  fn interval_split_and_shift() {
+    let interval = Interval::new(0, 10);
+
+    assert_eq!(interval.split_at(4), (Some(Interval::new(0, 4)), Some(Interval::new(4, 10))));
+    assert_eq!(interval.split_at(0), (None, Some(interval)));
+    assert_eq!(interval.shift(-3), Interval::new(-3, 7));
+    assert_eq!(interval.shift(i64::MAX).len(), 0);
+  }
+
+  #[test]
+  fn union_all_merges_overlapping_and_adjacent() {
+    let intervals = [Interval::new(5, 8), Interval::new(0, 2), Interval::new(2, 4), Interval::new(7, 9), Interval::new(6, 6)];
+
+    assert_eq!(union_all(intervals), vec![Interval::new(0, 4), Interval::new(5, 9)]);
+  }
+
+  #[test]
+  fn hyperrectangle_subtract_keeps_volume() {
+    let outer = Hyperrectangle::new([Interval::new(0, 4), Interval::new(0, 4), Interval::new(0, 4)]);
+    let inner = Hyperrectangle::new([Interval::new(1, 3), Interval::new(1, 5), Interval::new(-1, 2)]);
+
+    let pieces = outer.subtract(&inner);
+    let common = outer.intersection(&inner).unwrap();
+
+    assert_eq!(pieces.iter().map(|piece| piece.volume()).sum::<u128>() + common.volume(), outer.volume());
+    assert!(pieces.iter().all(|piece| piece.intersection(&inner).is_none()));
+    assert_eq!(outer.union(&inner).iter().map(|piece| piece.volume()).sum::<u128>(), 64 + 24 - 12);
+  }
+
+  #[test]
+  fn hyperrectangle_split_and_shift() {
+    let cube = Hyperrectangle::new([Interval::new(1, 4001); 4]);
+    let (below, above) = cube.split_at(2, 1000);
+
+    assert_eq!(below.unwrap().volume() + above.unwrap().volume(), 4000u128.pow(4));
+    assert_eq!(cube.split_at(0, 1), (None, Some(cube)));
+    assert!(cube.shift([1, 0, 0, -1]).contains([4001, 1, 1, 0]));
+  }
+}
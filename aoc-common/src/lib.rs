@@ -0,0 +1,23 @@
+pub mod config;
+pub mod cycle;
+pub mod differential;
+pub mod direction;
+pub mod error;
+pub mod extract;
+pub mod fuzz;
+pub mod generate;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod parser;
+pub mod random;
+pub mod report;
+pub mod solution;
+
+pub use direction::Direction;
+pub use error::Error;
+pub use grid::Grid;
+pub use solution::{Answer, Solution};
@@ -0,0 +1,139 @@
+/// Greatest common divisor by Euclid's algorithm; `gcd(0, 0)` is 0.
+pub fn gcd(a: usize, b: usize) -> usize {
+  let mut a = a;
+  let mut b = b;
+
+  while b != 0 {
+    (a, b) = (b, a % b);
+  }
+
+  a
+}
+
+/// Least common multiple, or `None` if it does not fit in a `usize`.
+pub fn checked_lcm(a: usize, b: usize) -> Option<usize> {
+  if a == 0 || b == 0 {
+    return Some(0);
+  }
+
+  (a / gcd(a, b)).checked_mul(b)
+}
+
+/// `(g, x, y)` such that `a * x + b * y = g`, where `g` is the non-negative gcd of `a` and `b`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+  let (mut old_r, mut r) = (a, b);
+  let (mut old_x, mut x) = (1, 0);
+  let (mut old_y, mut y) = (0, 1);
+
+  while r != 0 {
+    let quotient = old_r / r;
+
+    (old_r, r) = (r, old_r - quotient * r);
+    (old_x, x) = (x, old_x - quotient * x);
+    (old_y, y) = (y, old_y - quotient * y);
+  }
+
+  if old_r < 0 {
+    (-old_r, -old_x, -old_y)
+  } else {
+    (old_r, old_x, old_y)
+  }
+}
+
+/// `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`, if `a` and `modulus` are coprime.
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+  if modulus <= 0 {
+    return None;
+  }
+
+  match extended_gcd(a.rem_euclid(modulus), modulus) {
+    (1, x, _) => Some(x.rem_euclid(modulus)),
+    _ => None,
+  }
+}
+
+/// Smallest non-negative `t` with `t ≡ residue (mod modulus)` for every `(residue, modulus)` pair,
+/// together with the lcm of the moduli. The moduli need not be coprime. Returns `None` when the
+/// congruences contradict each other, a modulus is 0, or the lcm does not fit in a `usize`.
+pub fn crt(congruences: &[(usize, usize)]) -> Option<(usize, usize)> {
+  let mut result = (0i128, 1i128);
+
+  for &(residue, modulus) in congruences {
+    if modulus == 0 {
+      return None;
+    }
+
+    let (a1, m1) = result;
+    let (a2, m2) = ((residue % modulus) as i128, modulus as i128);
+
+    let (g, p, _) = extended_gcd(m1, m2);
+    if (a2 - a1) % g != 0 {
+      return None;
+    }
+
+    let step = m2 / g;
+    let k = ((a2 - a1) / g).rem_euclid(step).checked_mul(p.rem_euclid(step))?.rem_euclid(step);
+
+    let lcm = m1.checked_mul(step)?;
+    if lcm > usize::MAX as i128 {
+      return None;
+    }
+
+    result = ((a1 + m1 * k).rem_euclid(lcm), lcm);
+  }
+
+  Some((result.0 as usize, result.1 as usize))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn gcd_handles_zero_and_coprimes() {
+    assert_eq!(gcd(0, 0), 0);
+    assert_eq!(gcd(0, 7), 7);
+    assert_eq!(gcd(12, 18), 6);
+    assert_eq!(gcd(1_000_000_007, 998_244_353), 1);
+  }
+
+  #[test]
+  fn lcm_reports_overflow() {
+    assert_eq!(checked_lcm(4, 6), Some(12));
+    assert_eq!(checked_lcm(0, 6), Some(0));
+    assert_eq!(checked_lcm(usize::MAX, usize::MAX - 1), None);
+  }
+
+  #[test]
+  fn extended_gcd_satisfies_bezout() {
+    for (a, b) in [(240, 46), (-240, 46), (17, 0), (0, -5)] {
+      let (g, x, y) = extended_gcd(a, b);
+
+      assert_eq!(a * x + b * y, g);
+      assert!(g >= 0);
+    }
+  }
+
+  #[test]
+  fn inverse_exists_only_for_coprimes() {
+    assert_eq!(mod_inverse(3, 11), Some(4));
+    assert_eq!(mod_inverse(-3, 11), Some(7));
+    assert_eq!(mod_inverse(6, 9), None);
+  }
+
+  #[test]
+  fn crt_with_coprime_moduli() {
+    assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+  }
+
+  #[test]
+  fn crt_with_shared_factors() {
+    assert_eq!(crt(&[(3, 4), (5, 6)]), Some((11, 12)));
+    assert_eq!(crt(&[(0, 4), (1, 6)]), None);
+  }
+
+  #[test]
+  fn crt_of_nothing_is_zero() {
+    assert_eq!(crt(&[]), Some((0, 1)));
+  }
+}
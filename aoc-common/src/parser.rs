@@ -0,0 +1,370 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::error::Error;
+
+/// Why a parser did not match. `at` is the unparsed rest of the input where it gave up, and
+/// `length` the number of bytes of it that are at fault, by default the word or character there.
+/// A committed failure means the input had the right shape but an invalid value, so
+/// alternatives and repetitions pass it on instead of trying something else.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure<'a> {
+  pub at: &'a str,
+  pub length: usize,
+  pub message: String,
+  pub committed: bool,
+}
+
+/// The length of the run of alphanumeric characters `input` starts with, or else of its first
+/// character.
+fn token_length(input: &str) -> usize {
+  match input.find(|char: char| !char.is_alphanumeric()) {
+    Some(0) => input.chars().next().map_or(0, char::len_utf8),
+    Some(end) => end,
+    None => input.len(),
+  }
+}
+
+impl<'a> Failure<'a> {
+  pub fn expected(at: &'a str, message: impl Into<String>) -> Failure<'a> {
+    Failure{ at, length: token_length(at), message: message.into(), committed: false }
+  }
+
+  pub fn invalid(at: &'a str, message: impl Into<String>) -> Failure<'a> {
+    Failure{ at, length: token_length(at), message: message.into(), committed: true }
+  }
+
+  /// Blames the first `length` bytes of `at` instead.
+  pub fn spanning(self, length: usize) -> Failure<'a> {
+    Failure{ length: length.min(self.at.len()), ..self }
+  }
+
+  /// A parse error positioned within `line`, which `at` must be a slice of.
+  pub fn into_error(self, line_number: usize, line: &str) -> Error {
+    let token = self.at.get(..self.length).unwrap_or(self.at);
+
+    Error::parse_at(line_number, line, token, self.message)
+  }
+}
+
+/// The parsed value and the rest of the input.
+pub type Parsed<'a, T> = Result<(T, &'a str), Failure<'a>>;
+
+pub trait Parser<'a, T>: Fn(&'a str) -> Parsed<'a, T> {}
+
+impl<'a, T, F> Parser<'a, T> for F where F: Fn(&'a str) -> Parsed<'a, T> {}
+
+/// Runs `parser` over the whole of `line`, allowing only whitespace after it.
+pub fn parse_line<'a, T>(line_number: usize, line: &'a str, parser: impl Parser<'a, T>) -> Result<T, Error> {
+  let (value, rest) = match parser(line) {
+    Ok(parsed) => parsed,
+    Err(failure) => return Err(failure.into_error(line_number, line)),
+  };
+
+  let rest = rest.trim_start();
+  if !rest.is_empty() {
+    return Err(Failure::expected(rest, format!("unexpected \"{rest}\"")).spanning(rest.len()).into_error(line_number, line));
+  }
+
+  Ok(value)
+}
+
+/// Skips any whitespace.
+pub fn space0<'a>(input: &'a str) -> Parsed<'a, ()> {
+  Ok(((), input.trim_start()))
+}
+
+/// `parser` preceded by optional whitespace.
+pub fn ws<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, T> {
+  preceded(space0, parser)
+}
+
+/// Exactly the text `expected`.
+pub fn tag<'a>(expected: &'static str) -> impl Parser<'a, &'a str> {
+  move |input: &'a str| {
+    match input.strip_prefix(expected) {
+      Some(rest) => Ok((&input[..expected.len()], rest)),
+      None => Err(Failure::expected(input, format!("expected \"{expected}\""))),
+    }
+  }
+}
+
+/// A non-empty run of alphanumeric characters.
+pub fn identifier<'a>(input: &'a str) -> Parsed<'a, &'a str> {
+  let end = input.find(|char: char| !char.is_alphanumeric()).unwrap_or(input.len());
+
+  if end == 0 {
+    return Err(Failure::expected(input, "expected an identifier"));
+  }
+
+  Ok(input.split_at(end))
+}
+
+/// Decimal digits with an optional sign, converted with `FromStr`.
+pub fn number<'a, T>() -> impl Parser<'a, T>
+where
+  T: FromStr,
+  T::Err: Display,
+{
+  move |input: &'a str| {
+    let sign = if input.starts_with(['-', '+']) { 1 } else { 0 };
+    let digits = input[sign..].find(|char: char| !char.is_ascii_digit()).unwrap_or(input.len() - sign);
+
+    if digits == 0 {
+      return Err(Failure::expected(input, "expected a number"));
+    }
+
+    let (text, rest) = input.split_at(sign + digits);
+
+    match text.parse::<T>() {
+      Ok(value) => Ok((value, rest)),
+      Err(error) => Err(Failure::invalid(input, format!("invalid number \"{text}\": {error}")).spanning(text.len())),
+    }
+  }
+}
+
+/// The longest prefix whose characters all satisfy `predicate`, possibly empty.
+pub fn take_while<'a>(predicate: impl Fn(char) -> bool) -> impl Parser<'a, &'a str> {
+  move |input: &'a str| {
+    let end = input.find(|char: char| !predicate(char)).unwrap_or(input.len());
+
+    Ok(input.split_at(end))
+  }
+}
+
+/// Everything that is left.
+pub fn rest<'a>(input: &'a str) -> Parsed<'a, &'a str> {
+  Ok((input, &input[input.len()..]))
+}
+
+pub fn map<'a, A, B>(parser: impl Parser<'a, A>, f: impl Fn(A) -> B) -> impl Parser<'a, B> {
+  move |input: &'a str| parser(input).map(|(value, rest)| (f(value), rest))
+}
+
+/// Like [`map`], but `f` may reject the value; the failure points at where `parser` started.
+pub fn map_res<'a, A, B>(parser: impl Parser<'a, A>, f: impl Fn(A) -> Result<B, String>) -> impl Parser<'a, B> {
+  move |input: &'a str| {
+    let (value, rest) = match parser(input) {
+      Ok(parsed) => parsed,
+      Err(failure) => return Err(failure),
+    };
+
+    match f(value) {
+      Ok(value) => Ok((value, rest)),
+      Err(message) => Err(Failure::invalid(input, message).spanning(input.len() - rest.len())),
+    }
+  }
+}
+
+/// Like [`map_res`], for functions that position their own failures, e.g. the helpers in
+/// [`crate::extract`] applied to the text `parser` matched.
+pub fn and_then<'a, A, B>(parser: impl Parser<'a, A>, f: impl Fn(A) -> Result<B, Failure<'a>>) -> impl Parser<'a, B> {
+  move |input: &'a str| {
+    let (value, rest) = match parser(input) {
+      Ok(parsed) => parsed,
+      Err(failure) => return Err(failure),
+    };
+
+    f(value).map(|value| (value, rest))
+  }
+}
+
+/// `value` whenever `parser` matches.
+pub fn value<'a, A, T: Clone>(parser: impl Parser<'a, A>, value: T) -> impl Parser<'a, T> {
+  map(parser, move |_| value.clone())
+}
+
+pub fn pair<'a, A, B>(first: impl Parser<'a, A>, second: impl Parser<'a, B>) -> impl Parser<'a, (A, B)> {
+  move |input: &'a str| {
+    let (a, rest) = match first(input) {
+      Ok(parsed) => parsed,
+      Err(failure) => return Err(failure),
+    };
+
+    second(rest).map(|(b, rest)| ((a, b), rest))
+  }
+}
+
+pub fn preceded<'a, A, B>(first: impl Parser<'a, A>, second: impl Parser<'a, B>) -> impl Parser<'a, B> {
+  map(pair(first, second), |(_, b)| b)
+}
+
+pub fn terminated<'a, A, B>(first: impl Parser<'a, A>, second: impl Parser<'a, B>) -> impl Parser<'a, A> {
+  map(pair(first, second), |(a, _)| a)
+}
+
+pub fn delimited<'a, A, B, C>(open: impl Parser<'a, A>, inner: impl Parser<'a, B>, close: impl Parser<'a, C>) -> impl Parser<'a, B> {
+  preceded(open, terminated(inner, close))
+}
+
+pub fn separated_pair<'a, A, S, B>(first: impl Parser<'a, A>, separator: impl Parser<'a, S>, second: impl Parser<'a, B>) -> impl Parser<'a, (A, B)> {
+  pair(first, preceded(separator, second))
+}
+
+/// Tries `first`, then `second` on the same input. When both fail, reports the one that got further.
+pub fn alt<'a, T>(first: impl Parser<'a, T>, second: impl Parser<'a, T>) -> impl Parser<'a, T> {
+  move |input: &'a str| {
+    let first_failure = match first(input) {
+      Ok(parsed) => return Ok(parsed),
+      Err(failure) if failure.committed => return Err(failure),
+      Err(failure) => failure,
+    };
+
+    match second(input) {
+      Err(failure) if !failure.committed && first_failure.at.len() < failure.at.len() => Err(first_failure),
+      result => result,
+    }
+  }
+}
+
+pub fn opt<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Option<T>> {
+  move |input: &'a str| {
+    match parser(input) {
+      Ok((value, rest)) => Ok((Some(value), rest)),
+      Err(failure) if failure.committed => Err(failure),
+      Err(_) => Ok((None, input)),
+    }
+  }
+}
+
+/// Zero or more matches; stops at the first one that fails or consumes nothing.
+pub fn many0<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+  move |input: &'a str| {
+    let mut values = vec![];
+    let mut input = input;
+
+    loop {
+      match parser(input) {
+        Ok((value, rest)) if rest.len() < input.len() => {
+          values.push(value);
+          input = rest;
+        },
+        Ok(_) => return Ok((values, input)),
+        Err(failure) if failure.committed => return Err(failure),
+        Err(_) => return Ok((values, input)),
+      }
+    }
+  }
+}
+
+pub fn many1<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+  let many = many0(parser);
+
+  move |input: &'a str| {
+    match many(input) {
+      Ok((values, _)) if values.is_empty() => Err(Failure::expected(input, "expected at least one item")),
+      result => result,
+    }
+  }
+}
+
+/// Items separated by `separator`, possibly none. A separator must be followed by another item.
+pub fn separated_list0<'a, T, S>(item: impl Parser<'a, T>, separator: impl Parser<'a, S>) -> impl Parser<'a, Vec<T>> {
+  move |input: &'a str| {
+    let (first, mut input) = match item(input) {
+      Ok(parsed) => parsed,
+      Err(failure) if failure.committed => return Err(failure),
+      Err(_) => return Ok((vec![], input)),
+    };
+
+    let mut values = vec![first];
+
+    loop {
+      let rest = match separator(input) {
+        Ok((_, rest)) => rest,
+        Err(failure) if failure.committed => return Err(failure),
+        Err(_) => return Ok((values, input)),
+      };
+
+      let (value, rest) = match item(rest) {
+        Ok(parsed) => parsed,
+        Err(failure) => return Err(failure),
+      };
+
+      values.push(value);
+      input = rest;
+    }
+  }
+}
+
+pub fn separated_list1<'a, T, S>(item: impl Parser<'a, T>, separator: impl Parser<'a, S>) -> impl Parser<'a, Vec<T>> {
+  let list = separated_list0(item, separator);
+
+  move |input: &'a str| {
+    match list(input) {
+      Ok((values, _)) if values.is_empty() => Err(Failure::expected(input, "expected at least one item")),
+      result => result,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn sequences_and_numbers() {
+    let coordinate = separated_pair(number::<i32>(), ws(tag(",")), ws(number::<i32>()));
+
+    assert_eq!(coordinate("12, -3 rest"), Ok(((12, -3), " rest")));
+    assert_eq!(coordinate("12; 3").unwrap_err().at, "; 3");
+    assert!(number::<u8>()("300").unwrap_err().committed);
+  }
+
+  #[test]
+  fn alternatives_backtrack_unless_committed() {
+    let kind = alt(value(tag("%"), 1), alt(value(tag("&"), 2), value(identifier, 3)));
+
+    assert_eq!(kind("&inv"), Ok((2, "inv")));
+    assert_eq!(kind("broadcaster"), Ok((3, "")));
+
+    let checked = alt(map_res(identifier, |name| if name == "x" { Ok(0) } else { Err(format!("bad \"{name}\"")) }), value(tag("y"), 1));
+    assert_eq!(checked("y").unwrap_err().message, "bad \"y\"");
+  }
+
+  #[test]
+  fn alternatives_report_furthest_failure() {
+    let either = alt(pair(tag("ab"), tag("c")), pair(tag("a"), tag("x")));
+
+    assert_eq!(either("abd").unwrap_err().at, "d");
+  }
+
+  #[test]
+  fn lists_and_repetition() {
+    let list = separated_list0(ws(identifier), ws(tag(",")));
+
+    assert_eq!(list(" a, b,c"), Ok((vec!["a", "b", "c"], "")));
+    assert_eq!(list(""), Ok((vec![], "")));
+    assert!(list("a,").is_err());
+    assert_eq!(many1(ws(number::<u32>()))(" 1 2 | 3"), Ok((vec![1, 2], " | 3")));
+    assert!(many1(ws(number::<u32>()))(" | 3").is_err());
+  }
+
+  #[test]
+  fn take_while_and_rest() {
+    let halves = separated_pair(take_while(|char| char != '|'), tag("|"), rest);
+
+    assert_eq!(halves("1 2 | 3"), Ok((("1 2 ", " 3"), "")));
+    assert_eq!(take_while(char::is_alphabetic)("123"), Ok(("", "123")));
+  }
+
+  #[test]
+  fn parse_line_positions_errors() {
+    let game = preceded(tag("Game "), number::<u32>());
+
+    assert_eq!(parse_line(3, "Game 7  ", &game), Ok(7));
+    assert_eq!(parse_line(3, "Game x", &game), Err(Error::parse(3, 6, "expected a number").spanning(1)));
+    assert_eq!(parse_line(3, "Game 7 extra", &game), Err(Error::parse(3, 8, "unexpected \"extra\"").spanning(5)));
+  }
+
+  #[test]
+  fn failures_span_the_offending_token() {
+    let line = "a<2006:qkq,m>-:A";
+    let rule = separated_pair(identifier, tag("<"), map_res(number::<u64>(), |value| if value < 1000 { Ok(value) } else { Err(String::from("too large")) }));
+
+    assert_eq!(parse_line(1, line, &rule), Err(Error::parse(1, 3, "too large").spanning(4)));
+    assert_eq!(number::<u8>()("-300:").unwrap_err().length, 4);
+    assert_eq!(tag("x")("qkq,").unwrap_err().length, 3);
+    assert_eq!(tag("x")(":A").unwrap_err().length, 1);
+    assert_eq!(tag("x")("").unwrap_err().length, 0);
+  }
+}
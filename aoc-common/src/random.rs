@@ -0,0 +1,79 @@
+use std::ops::Range;
+
+/// A small seeded pseudo-random generator (SplitMix64). The same seed always gives the same
+/// sequence, so a generated input can be reproduced from its seed alone.
+#[derive(Debug, Clone)]
+pub struct Rng {
+  state: u64,
+}
+
+impl Rng {
+  pub fn new(seed: u64) -> Rng {
+    Rng{ state: seed }
+  }
+
+  pub fn next_u64(&mut self) -> u64 {
+    self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+
+    let mut value = self.state;
+    value = (value ^ (value >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94d049bb133111eb);
+
+    value ^ (value >> 31)
+  }
+
+  /// A value in `range`, which must not be empty.
+  pub fn range(&mut self, range: Range<i64>) -> i64 {
+    let width = range.end.abs_diff(range.start);
+
+    range.start.wrapping_add(((self.next_u64() as u128 * width as u128) >> 64) as i64)
+  }
+
+  /// An index below `count`, which must not be zero.
+  pub fn below(&mut self, count: usize) -> usize {
+    ((self.next_u64() as u128 * count as u128) >> 64) as usize
+  }
+
+  /// `true` with probability `numerator / denominator`.
+  pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+    self.next_u64() % denominator < numerator
+  }
+
+  /// A random element of `items`, which must not be empty.
+  pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+    &items[self.below(items.len())]
+  }
+
+  /// Shuffles `items` in place by Fisher-Yates.
+  pub fn shuffle<T>(&mut self, items: &mut [T]) {
+    for index in (1..items.len()).rev() {
+      items.swap(index, self.below(index + 1));
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn same_seed_same_sequence() {
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+
+    assert_eq!((0..8).map(|_| a.next_u64()).collect::<Vec<_>>(), (0..8).map(|_| b.next_u64()).collect::<Vec<_>>());
+    assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+  }
+
+  #[test]
+  fn values_stay_in_range() {
+    let mut rng = Rng::new(7);
+
+    for _ in 0..1000 {
+      assert!((-3..4).contains(&rng.range(-3..4)));
+      assert!(rng.below(5) < 5);
+    }
+
+    assert_eq!(rng.range(i64::MIN..i64::MIN + 1), i64::MIN);
+  }
+}
@@ -0,0 +1,181 @@
+use std::time::Duration;
+
+use crate::{error::Error, input::normalize, solution::Answer};
+
+/// How a day binary prints its results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+  /// `part1: <answer>` lines for people.
+  Text,
+  /// One JSON object per line.
+  Json,
+  /// A header row followed by one row per record.
+  Csv,
+}
+
+impl Format {
+  pub fn from_arg(arg: &str) -> Option<Format> {
+    match arg {
+      "text" => Some(Format::Text),
+      "json" => Some(Format::Json),
+      "csv" => Some(Format::Csv),
+      _ => None,
+    }
+  }
+}
+
+/// The outcome of solving one part, with the time taken to parse the input, which every part of a
+/// day shares, and the time taken by the part itself.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+  pub day: u8,
+  pub part: u8,
+  pub result: Result<Answer, Error>,
+  pub parse: Duration,
+  pub elapsed: Duration,
+}
+
+fn json_string(value: &str) -> String {
+  let mut escaped = String::from("\"");
+
+  for char in value.chars() {
+    match char {
+      '"' => escaped.push_str("\\\""),
+      '\\' => escaped.push_str("\\\\"),
+      '\n' => escaped.push_str("\\n"),
+      '\r' => escaped.push_str("\\r"),
+      '\t' => escaped.push_str("\\t"),
+      char if char.is_control() => escaped.push_str(&format!("\\u{:04x}", char as u32)),
+      char => escaped.push(char),
+    }
+  }
+
+  escaped.push('"');
+  escaped
+}
+
+fn csv_field(value: &str) -> String {
+  if value.contains([',', '"', '\n', '\r']) {
+    format!("\"{}\"", value.replace('"', "\"\""))
+  } else {
+    value.to_string()
+  }
+}
+
+/// Renders the records in `format`. Answers are numbers and parse and part times are in nanoseconds;
+/// the answer of a failed part and the error of a solved one are `null` in JSON and empty in CSV.
+pub fn render(records: &[Record], format: Format) -> String {
+  let mut output = String::new();
+
+  if format == Format::Csv {
+    output.push_str("day,part,answer,parse_ns,elapsed_ns,error\n");
+  }
+
+  for record in records {
+    let (answer, error) = match &record.result {
+      Ok(answer) => (Some(answer.to_string()), None),
+      Err(error) => (None, Some(error.to_string())),
+    };
+
+    let line = match format {
+      Format::Text => format!("part{}: {}", record.part, answer.or(error).unwrap_or_default()),
+      Format::Json => format!(
+        "{{\"day\":{},\"part\":{},\"answer\":{},\"parse_ns\":{},\"elapsed_ns\":{},\"error\":{}}}",
+        record.day,
+        record.part,
+        answer.unwrap_or_else(|| String::from("null")),
+        record.parse.as_nanos(),
+        record.elapsed.as_nanos(),
+        error.map_or_else(|| String::from("null"), |error| json_string(&error)),
+      ),
+      Format::Csv => format!(
+        "{},{},{},{},{},{}",
+        record.day,
+        record.part,
+        answer.unwrap_or_default(),
+        record.parse.as_nanos(),
+        record.elapsed.as_nanos(),
+        csv_field(&error.unwrap_or_default()),
+      ),
+    };
+
+    output.push_str(&line);
+    output.push('\n');
+  }
+
+  output
+}
+
+/// Each distinct parse error among `errors` followed by the line of `contents` it points at, with
+/// carets under the offending token. Other errors are left out, as they have nothing to point at.
+pub fn diagnostics<'a>(errors: impl IntoIterator<Item = &'a Error>, contents: &str) -> String {
+  let contents = normalize(contents);
+  let mut seen = vec![];
+  let mut output = String::new();
+
+  for error in errors {
+    if seen.contains(&error) {
+      continue;
+    }
+    seen.push(error);
+
+    if let Some(excerpt) = error.excerpt(&contents) {
+      output.push_str(&format!("error: {error}\n{excerpt}"));
+    }
+  }
+
+  output
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn records() -> Vec<Record> {
+    vec![
+      Record{ day: 3, part: 1, result: Ok(Answer::Unsigned(4361)), parse: Duration::from_micros(3), elapsed: Duration::from_micros(12) },
+      Record{ day: 3, part: 2, result: Err(Error::parse(2, 5, "unexpected \"x\", expected a digit")), parse: Duration::from_micros(3), elapsed: Duration::from_nanos(800) },
+    ]
+  }
+
+  #[test]
+  fn text_matches_plain_output() {
+    assert_eq!(render(&records(), Format::Text), "part1: 4361\npart2: parse error on line 2, column 5: unexpected \"x\", expected a digit\n");
+  }
+
+  #[test]
+  fn json_escapes_errors() {
+    assert_eq!(
+      render(&records(), Format::Json),
+      "{\"day\":3,\"part\":1,\"answer\":4361,\"parse_ns\":3000,\"elapsed_ns\":12000,\"error\":null}\n\
+       {\"day\":3,\"part\":2,\"answer\":null,\"parse_ns\":3000,\"elapsed_ns\":800,\"error\":\"parse error on line 2, column 5: unexpected \\\"x\\\", expected a digit\"}\n",
+    );
+  }
+
+  #[test]
+  fn csv_quotes_errors() {
+    assert_eq!(
+      render(&records(), Format::Csv),
+      "day,part,answer,parse_ns,elapsed_ns,error\n\
+       3,1,4361,3000,12000,\n\
+       3,2,,3000,800,\"parse error on line 2, column 5: unexpected \"\"x\"\", expected a digit\"\n",
+    );
+  }
+
+  #[test]
+  fn diagnostics_point_into_the_input_once_per_error() {
+    let error = Error::parse(2, 2, "unexpected \"x\", expected a digit").spanning(1);
+    let errors = [error.clone(), error, Error::unsolvable("no gears")];
+
+    assert_eq!(
+      diagnostics(&errors, "\u{feff}467..114\r\n.x.*....\r\n"),
+      "error: parse error on line 2, column 2: unexpected \"x\", expected a digit\n  |\n2 | .x.*....\n  |  ^\n",
+    );
+  }
+
+  #[test]
+  fn formats_parse_from_flag_values() {
+    assert_eq!(Format::from_arg("json"), Some(Format::Json));
+    assert_eq!(Format::from_arg("xml"), None);
+  }
+}
@@ -0,0 +1,228 @@
+use std::{env, fmt, process, time::Instant};
+
+use crate::{config::{Config, from_flags}, error::Error, input::{InputSource, normalize}, report::{Format, Record, diagnostics, render}};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Answer {
+  Signed(i64),
+  Unsigned(u64),
+}
+
+impl fmt::Display for Answer {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Answer::Signed(value) => write!(f, "{value}"),
+      Answer::Unsigned(value) => write!(f, "{value}"),
+    }
+  }
+}
+
+impl From<i64> for Answer {
+  fn from(value: i64) -> Self {
+    Answer::Signed(value)
+  }
+}
+
+impl From<u64> for Answer {
+  fn from(value: u64) -> Self {
+    Answer::Unsigned(value)
+  }
+}
+
+impl From<u32> for Answer {
+  fn from(value: u32) -> Self {
+    Answer::Unsigned(value as u64)
+  }
+}
+
+impl From<usize> for Answer {
+  fn from(value: usize) -> Self {
+    Answer::Unsigned(value as u64)
+  }
+}
+
+/// A day's puzzle: the input is parsed once and then shared by both parts, which also get the
+/// puzzle's constants.
+pub trait Solution {
+  type Input<'a>;
+
+  /// `()` for days without constants.
+  type Config: Config;
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error>;
+
+  fn part1(input: &Self::Input<'_>, config: &Self::Config) -> Result<Answer, Error>;
+
+  fn part2(input: &Self::Input<'_>, config: &Self::Config) -> Result<Answer, Error>;
+}
+
+/// Solves the parts with the puzzle's own constants.
+pub fn solve<S: Solution>(contents: &str, parts: &[u8]) -> Vec<Result<Answer, Error>> {
+  solve_with::<S>(contents, parts, &S::Config::default())
+}
+
+pub fn solve_with<S: Solution>(contents: &str, parts: &[u8], config: &S::Config) -> Vec<Result<Answer, Error>> {
+  let contents = normalize(contents);
+
+  let input = match S::parse(&contents) {
+    Ok(input) => input,
+    Err(error) => return parts.iter().map(|_| Err(error.clone())).collect(),
+  };
+
+  parts.iter().map(|&part| solve_part::<S>(&input, part, config)).collect()
+}
+
+fn solve_part<S: Solution>(input: &S::Input<'_>, part: u8, config: &S::Config) -> Result<Answer, Error> {
+  match part {
+    1 => S::part1(input, config),
+    2 => S::part2(input, config),
+    _ => Err(Error::unsolvable(format!("unknown part {part}"))),
+  }
+}
+
+/// Like [`solve_with`], with the constants set by `(name, value)` flags. Fails if a flag is
+/// unknown or its value invalid.
+pub fn solve_configured<S: Solution>(contents: &str, parts: &[u8], flags: &[(String, String)]) -> Result<Vec<Result<Answer, Error>>, String> {
+  from_flags::<S::Config>(flags).map(|config| solve_with::<S>(contents, parts, &config))
+}
+
+/// Parses the input once and solves each part from it, timing the parse and every part apart.
+pub fn solve_timed<S: Solution>(day: u8, contents: &str, parts: &[u8], config: &S::Config) -> Vec<Record> {
+  let contents = normalize(contents);
+
+  let start = Instant::now();
+  let input = S::parse(&contents);
+  let parse = start.elapsed();
+
+  parts
+    .iter()
+    .map(|&part| {
+      let start = Instant::now();
+      let result = match &input {
+        Ok(input) => solve_part::<S>(input, part, config),
+        Err(error) => Err(error.clone()),
+      };
+
+      Record{ day, part, result, parse, elapsed: start.elapsed() }
+    })
+    .collect()
+}
+
+/// Prints the answers in `format`, and an excerpt of the input for each parse error to stderr.
+pub fn print_answers<S: Solution>(day: u8, contents: &str, format: Format, config: &S::Config) {
+  let records = solve_timed::<S>(day, contents, &[1, 2], config);
+
+  print!("{}", render(&records, format));
+  eprint!("{}", diagnostics(records.iter().filter_map(|record| record.result.as_ref().err()), contents));
+}
+
+fn usage<C: Config>() -> String {
+  let flags = C::FLAGS.iter().map(|(name, value)| format!(" [--{name} <{value}>]")).collect::<String>();
+
+  format!("usage: {} [--format <text|json|csv>]{flags} [<input path> | -]", env::args().next().unwrap_or_default())
+}
+
+/// Entry point shared by the day binaries: `day-NN [--format <text|json|csv>] [--<constant> <value>]...
+/// [<path> | -]`, reading `default_input` when no path is given. Exits with status 1 on bad
+/// arguments or if the input cannot be read.
+pub fn run<S: Solution>(day: u8, default_input: &str) {
+  let mut format = Format::Text;
+  let mut flags = vec![];
+  let mut path = None;
+
+  let mut args = env::args().skip(1);
+  while let Some(arg) = args.next() {
+    if arg == "--format" {
+      format = match args.next().as_deref().and_then(Format::from_arg) {
+        Some(format) => format,
+        None => {
+          eprintln!("error: expected \"text\", \"json\" or \"csv\" after \"--format\"");
+          eprintln!("{}", usage::<S::Config>());
+          process::exit(1);
+        },
+      };
+    } else if let Some(name) = arg.strip_prefix("--") {
+      match args.next() {
+        Some(value) => flags.push((name.to_string(), value)),
+        None => {
+          eprintln!("error: missing value for \"{arg}\"");
+          eprintln!("{}", usage::<S::Config>());
+          process::exit(1);
+        },
+      }
+    } else if path.is_none() {
+      path = Some(arg);
+    } else {
+      eprintln!("error: too many arguments");
+      eprintln!("{}", usage::<S::Config>());
+      process::exit(1);
+    }
+  }
+
+  let config = match from_flags::<S::Config>(&flags) {
+    Ok(config) => config,
+    Err(error) => {
+      eprintln!("error: {error}");
+      eprintln!("{}", usage::<S::Config>());
+      process::exit(1);
+    },
+  };
+
+  let source = InputSource::from_arg_or_default(path.as_deref(), default_input);
+
+  match source.read() {
+    Ok(contents) => print_answers::<S>(day, &contents, format, &config),
+    Err(error) => {
+      eprintln!("error: {error}");
+      process::exit(1);
+    },
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::atomic::{AtomicUsize, Ordering};
+
+  use super::*;
+
+  static PARSES: AtomicUsize = AtomicUsize::new(0);
+
+  struct Length;
+
+  impl Solution for Length {
+    type Input<'a> = usize;
+
+    type Config = ();
+
+    fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+      PARSES.fetch_add(1, Ordering::SeqCst);
+
+      match contents.len() {
+        0 => Err(Error::parse(1, 0, "empty input")),
+        length => Ok(length),
+      }
+    }
+
+    fn part1(length: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+      Ok((*length).into())
+    }
+
+    fn part2(length: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+      Ok((*length * 2).into())
+    }
+  }
+
+  #[test]
+  fn timed_parts_share_one_parse() {
+    let before = PARSES.load(Ordering::SeqCst);
+    let records = solve_timed::<Length>(1, "abc\r\ndef", &[1, 2], &());
+
+    assert_eq!(PARSES.load(Ordering::SeqCst) - before, 1);
+    assert_eq!(records.iter().map(|record| record.result.clone()).collect::<Vec<_>>(), [Ok(Answer::Unsigned(7)), Ok(Answer::Unsigned(14))]);
+    assert_eq!(records[0].parse, records[1].parse);
+
+    let records = solve_timed::<Length>(1, "", &[1, 2], &());
+
+    assert!(records.iter().all(|record| record.result == Err(Error::parse(1, 0, "empty input"))));
+  }
+}
@@ -0,0 +1,68 @@
+use aoc_common::Error;
+
+/// Known-good answers for one day, stored next to its input as `day-NN/answers.txt`
+/// in the same `part1: <answer>` format the day binaries print.
+pub struct Answers {
+  parts: [Option<String>; 2],
+}
+
+impl Answers {
+  pub fn get(&self, part: u8) -> Option<&str> {
+    match part {
+      1 | 2 => self.parts[part as usize - 1].as_deref(),
+      _ => None,
+    }
+  }
+}
+
+pub fn parse_answers(contents: &str) -> Result<Answers, Error> {
+  let mut parts = [None, None];
+
+  for (index, line) in contents.lines().enumerate() {
+    let line = line.trim();
+    if line.is_empty() {
+      continue;
+    }
+
+    let (part, answer) = match line.split_once(':') {
+      Some(pair) => pair,
+      None => return Err(Error::parse(index + 1, 0, "missing \":\"")),
+    };
+
+    let part = match part.trim() {
+      "part1" => 0,
+      "part2" => 1,
+      part => return Err(Error::parse(index + 1, 1, format!("unknown part \"{part}\""))),
+    };
+
+    parts[part] = Some(answer.trim().to_string());
+  }
+
+  Ok(Answers{ parts })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parses_both_parts() {
+    let answers = parse_answers("part1: 35\npart2: -46\n").unwrap();
+
+    assert_eq!(answers.get(1), Some("35"));
+    assert_eq!(answers.get(2), Some("-46"));
+  }
+
+  #[test]
+  fn missing_part_is_none() {
+    let answers = parse_answers("part2: 46\n").unwrap();
+
+    assert_eq!(answers.get(1), None);
+    assert_eq!(answers.get(2), Some("46"));
+  }
+
+  #[test]
+  fn rejects_unknown_part() {
+    assert!(parse_answers("part3: 1\n").is_err());
+  }
+}
@@ -0,0 +1,139 @@
+use std::{collections::HashMap, hint, time::{Duration, Instant}};
+
+use aoc_common::{Error, Solution, input::normalize};
+
+pub const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+
+pub struct Timings {
+  pub min: Duration,
+  pub median: Duration,
+  pub max: Duration,
+}
+
+fn summarize(mut samples: Vec<Duration>) -> Option<Timings> {
+  if samples.is_empty() {
+    return None;
+  }
+
+  samples.sort();
+
+  Some(Timings{
+    min: samples[0],
+    median: samples[samples.len() / 2],
+    max: samples[samples.len() - 1],
+  })
+}
+
+/// Times parse, part1 and part2 separately over `runs` runs. A part that returns an
+/// error is not timed, so its entry is `None`.
+pub fn bench<S: Solution>(contents: &str, runs: usize) -> Result<[Option<Timings>; 3], Error> {
+  let contents = normalize(contents);
+  let config = S::Config::default();
+
+  let mut parse_samples = Vec::with_capacity(runs);
+  let mut part1_samples = Vec::with_capacity(runs);
+  let mut part2_samples = Vec::with_capacity(runs);
+
+  for _ in 0..runs {
+    let start = Instant::now();
+    let input = S::parse(hint::black_box(&contents))?;
+    parse_samples.push(start.elapsed());
+
+    let start = Instant::now();
+    if hint::black_box(S::part1(&input, &config)).is_ok() {
+      part1_samples.push(start.elapsed());
+    }
+
+    let start = Instant::now();
+    if hint::black_box(S::part2(&input, &config)).is_ok() {
+      part2_samples.push(start.elapsed());
+    }
+  }
+
+  Ok([summarize(parse_samples), summarize(part1_samples), summarize(part2_samples)])
+}
+
+/// Baseline medians keyed by day and stage, stored one `<day> <stage> <nanoseconds>` per line.
+pub type Baseline = HashMap<(u8, String), u128>;
+
+pub fn parse_baseline(contents: &str) -> Result<Baseline, Error> {
+  let mut baseline = Baseline::new();
+
+  for (index, line) in contents.lines().enumerate() {
+    if line.trim().is_empty() {
+      continue;
+    }
+
+    let fields = line.split_whitespace().collect::<Vec<&str>>();
+    if fields.len() != 3 {
+      return Err(Error::parse(index + 1, 0, "expected \"<day> <stage> <nanoseconds>\""));
+    }
+
+    let day = match fields[0].parse::<u8>() {
+      Ok(day) => day,
+      Err(error) => return Err(Error::parse_at(index + 1, line, fields[0], format!("invalid day \"{}\": {error}", fields[0]))),
+    };
+    let nanos = match fields[2].parse::<u128>() {
+      Ok(nanos) => nanos,
+      Err(error) => return Err(Error::parse_at(index + 1, line, fields[2], format!("invalid duration \"{}\": {error}", fields[2]))),
+    };
+
+    baseline.insert((day, fields[1].to_string()), nanos);
+  }
+
+  Ok(baseline)
+}
+
+pub fn format_baseline(baseline: &Baseline) -> String {
+  let mut entries = baseline.iter().collect::<Vec<_>>();
+  entries.sort_by_key(|((day, stage), _)| (*day, STAGES.iter().position(|candidate| candidate == stage)));
+
+  entries
+    .into_iter()
+    .map(|((day, stage), nanos)| format!("{day:02} {stage} {nanos}\n"))
+    .collect()
+}
+
+/// Percentage change of `current` relative to `baseline`; positive means slower.
+pub fn change_percent(baseline: u128, current: u128) -> f64 {
+  if baseline == 0 {
+    return 0f64;
+  }
+
+  (current as f64 - baseline as f64) / baseline as f64 * 100f64
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn baseline_round_trip() {
+    let mut baseline = Baseline::new();
+    baseline.insert((5, String::from("part2")), 1500);
+    baseline.insert((5, String::from("parse")), 20);
+    baseline.insert((1, String::from("part1")), 300);
+
+    let contents = format_baseline(&baseline);
+
+    assert_eq!(contents, "01 part1 300\n05 parse 20\n05 part2 1500\n");
+    assert_eq!(parse_baseline(&contents).unwrap(), baseline);
+  }
+
+  #[test]
+  fn change_is_relative_to_baseline() {
+    assert_eq!(change_percent(200, 250), 25f64);
+    assert_eq!(change_percent(200, 100), -50f64);
+  }
+
+  #[test]
+  fn median_of_sorted_samples() {
+    let samples = [5, 1, 3].map(Duration::from_nanos).to_vec();
+
+    let timings = summarize(samples).unwrap();
+
+    assert_eq!(timings.min, Duration::from_nanos(1));
+    assert_eq!(timings.median, Duration::from_nanos(3));
+    assert_eq!(timings.max, Duration::from_nanos(5));
+  }
+}
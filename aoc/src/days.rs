@@ -0,0 +1,44 @@
+use aoc_common::{Answer, Error, random::Rng, solution::{solve, solve_configured}};
+
+use crate::bench::{Timings, bench};
+
+#[allow(clippy::type_complexity)]
+pub struct Day {
+  pub number: u8,
+  pub solve: fn(&str, &[u8]) -> Vec<Result<Answer, Error>>,
+  /// Like `solve`, with the puzzle constants set by `(name, value)` flags.
+  pub solve_configured: fn(&str, &[u8], &[(String, String)]) -> Result<Vec<Result<Answer, Error>>, String>,
+  pub bench: fn(&str, usize) -> Result<[Option<Timings>; 3], Error>,
+  pub generate: fn(&mut Rng, usize) -> String,
+}
+
+pub const DAYS: [Day; 24] = [
+  Day{ number: 1, solve: solve::<day_01::Day01>, solve_configured: solve_configured::<day_01::Day01>, bench: bench::<day_01::Day01>, generate: day_01::generate },
+  Day{ number: 2, solve: solve::<day_02::Day02>, solve_configured: solve_configured::<day_02::Day02>, bench: bench::<day_02::Day02>, generate: day_02::generate },
+  Day{ number: 3, solve: solve::<day_03::Day03>, solve_configured: solve_configured::<day_03::Day03>, bench: bench::<day_03::Day03>, generate: day_03::generate },
+  Day{ number: 4, solve: solve::<day_04::Day04>, solve_configured: solve_configured::<day_04::Day04>, bench: bench::<day_04::Day04>, generate: day_04::generate },
+  Day{ number: 5, solve: solve::<day_05::Day05>, solve_configured: solve_configured::<day_05::Day05>, bench: bench::<day_05::Day05>, generate: day_05::generate },
+  Day{ number: 6, solve: solve::<day_06::Day06>, solve_configured: solve_configured::<day_06::Day06>, bench: bench::<day_06::Day06>, generate: day_06::generate },
+  Day{ number: 7, solve: solve::<day_07::Day07>, solve_configured: solve_configured::<day_07::Day07>, bench: bench::<day_07::Day07>, generate: day_07::generate },
+  Day{ number: 8, solve: solve::<day_08::Day08>, solve_configured: solve_configured::<day_08::Day08>, bench: bench::<day_08::Day08>, generate: day_08::generate },
+  Day{ number: 9, solve: solve::<day_09::Day09>, solve_configured: solve_configured::<day_09::Day09>, bench: bench::<day_09::Day09>, generate: day_09::generate },
+  Day{ number: 10, solve: solve::<day_10::Day10>, solve_configured: solve_configured::<day_10::Day10>, bench: bench::<day_10::Day10>, generate: day_10::generate },
+  Day{ number: 11, solve: solve::<day_11::Day11>, solve_configured: solve_configured::<day_11::Day11>, bench: bench::<day_11::Day11>, generate: day_11::generate },
+  Day{ number: 12, solve: solve::<day_12::Day12>, solve_configured: solve_configured::<day_12::Day12>, bench: bench::<day_12::Day12>, generate: day_12::generate },
+  Day{ number: 13, solve: solve::<day_13::Day13>, solve_configured: solve_configured::<day_13::Day13>, bench: bench::<day_13::Day13>, generate: day_13::generate },
+  Day{ number: 14, solve: solve::<day_14::Day14>, solve_configured: solve_configured::<day_14::Day14>, bench: bench::<day_14::Day14>, generate: day_14::generate },
+  Day{ number: 15, solve: solve::<day_15::Day15>, solve_configured: solve_configured::<day_15::Day15>, bench: bench::<day_15::Day15>, generate: day_15::generate },
+  Day{ number: 16, solve: solve::<day_16::Day16>, solve_configured: solve_configured::<day_16::Day16>, bench: bench::<day_16::Day16>, generate: day_16::generate },
+  Day{ number: 17, solve: solve::<day_17::Day17>, solve_configured: solve_configured::<day_17::Day17>, bench: bench::<day_17::Day17>, generate: day_17::generate },
+  Day{ number: 18, solve: solve::<day_18::Day18>, solve_configured: solve_configured::<day_18::Day18>, bench: bench::<day_18::Day18>, generate: day_18::generate },
+  Day{ number: 19, solve: solve::<day_19::Day19>, solve_configured: solve_configured::<day_19::Day19>, bench: bench::<day_19::Day19>, generate: day_19::generate },
+  Day{ number: 20, solve: solve::<day_20::Day20>, solve_configured: solve_configured::<day_20::Day20>, bench: bench::<day_20::Day20>, generate: day_20::generate },
+  Day{ number: 21, solve: solve::<day_21::Day21>, solve_configured: solve_configured::<day_21::Day21>, bench: bench::<day_21::Day21>, generate: day_21::generate },
+  Day{ number: 22, solve: solve::<day_22::Day22>, solve_configured: solve_configured::<day_22::Day22>, bench: bench::<day_22::Day22>, generate: day_22::generate },
+  Day{ number: 23, solve: solve::<day_23::Day23>, solve_configured: solve_configured::<day_23::Day23>, bench: bench::<day_23::Day23>, generate: day_23::generate },
+  Day{ number: 24, solve: solve::<day_24::Day24>, solve_configured: solve_configured::<day_24::Day24>, bench: bench::<day_24::Day24>, generate: day_24::generate },
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+  DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,561 @@
+use std::{env, fs, panic, path::Path, process, sync::Arc, thread, time::Duration};
+
+use aoc_common::{Error, input::InputSource, random::Rng, report};
+
+use crate::pool::{Outcome, Task};
+
+mod answers;
+mod bench;
+mod days;
+mod pool;
+
+const USAGE: &str = "usage: aoc run <day> [--part <1|2>] [--input <path> | -] [--<constant> <value>]...
+       aoc run --all [--jobs <n>] [--timeout <seconds>]
+       aoc verify [<day>]
+       aoc bench [<day>] [--runs <n>] [--threshold <percent>] [--save]
+       aoc generate <day> [--seed <n>] [--size <n>] [--output <path>]";
+
+
+struct RunOptions {
+  day: Option<u8>,
+  part: Option<u8>,
+  input: Option<String>,
+  all: bool,
+  jobs: Option<usize>,
+  timeout: Option<Duration>,
+  /// Puzzle constants as `(name, value)` pairs, checked by the day's config.
+  flags: Vec<(String, String)>,
+}
+
+fn parse_run_options(args: &[String]) -> Result<RunOptions, String> {
+  let mut options = RunOptions{ day: None, part: None, input: None, all: false, jobs: None, timeout: None, flags: vec![] };
+
+  let mut args = args.iter();
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "--all" => {
+        options.all = true;
+      },
+      "--part" => {
+        let value = match args.next() {
+          Some(value) => value,
+          None => return Err("missing value for \"--part\"".into()),
+        };
+
+        options.part = match value.as_str() {
+          "1" => Some(1),
+          "2" => Some(2),
+          _ => return Err(format!("invalid part \"{value}\", expected 1 or 2")),
+        };
+      },
+      "--input" => {
+        options.input = match args.next() {
+          Some(value) => Some(value.clone()),
+          None => return Err("missing value for \"--input\"".into()),
+        };
+      },
+      "--jobs" => {
+        options.jobs = match args.next().map(|value| value.parse::<usize>()) {
+          Some(Ok(jobs)) if jobs > 0 => Some(jobs),
+          Some(_) => return Err(String::from("invalid value for \"--jobs\", expected a positive number")),
+          None => return Err(String::from("missing value for \"--jobs\"")),
+        };
+      },
+      "--timeout" => {
+        options.timeout = match args.next().map(|value| value.parse::<f64>()) {
+          Some(Ok(seconds)) if seconds > 0f64 && seconds.is_finite() => Some(Duration::from_secs_f64(seconds)),
+          Some(_) => return Err(String::from("invalid value for \"--timeout\", expected a positive number of seconds")),
+          None => return Err(String::from("missing value for \"--timeout\"")),
+        };
+      },
+      _ if arg.starts_with("--") => {
+        match args.next() {
+          Some(value) => options.flags.push((arg[2..].to_string(), value.clone())),
+          None => return Err(format!("missing value for \"{arg}\"")),
+        }
+      },
+      _ => {
+        options.day = Some(parse_day(arg)?);
+      },
+    }
+  }
+
+  if options.all && (options.day.is_some() || options.part.is_some() || options.input.is_some() || !options.flags.is_empty()) {
+    return Err("\"--all\" cannot be combined with a day, \"--part\", \"--input\" or puzzle constants".into());
+  }
+
+  if !options.all && (options.jobs.is_some() || options.timeout.is_some()) {
+    return Err("\"--jobs\" and \"--timeout\" only apply to \"--all\"".into());
+  }
+
+  Ok(options)
+}
+
+struct BenchOptions {
+  day: Option<u8>,
+  runs: usize,
+  threshold: f64,
+  save: bool,
+}
+
+fn parse_bench_options(args: &[String]) -> Result<BenchOptions, String> {
+  let mut options = BenchOptions{ day: None, runs: 10, threshold: 10f64, save: false };
+
+  let mut args = args.iter();
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "--runs" => {
+        options.runs = match args.next().map(|value| value.parse::<usize>()) {
+          Some(Ok(runs)) if runs > 0 => runs,
+          Some(_) => return Err(String::from("invalid value for \"--runs\", expected a positive number")),
+          None => return Err(String::from("missing value for \"--runs\"")),
+        };
+      },
+      "--threshold" => {
+        options.threshold = match args.next().map(|value| value.parse::<f64>()) {
+          Some(Ok(threshold)) if threshold >= 0f64 => threshold,
+          Some(_) => return Err(String::from("invalid value for \"--threshold\", expected a percentage")),
+          None => return Err(String::from("missing value for \"--threshold\"")),
+        };
+      },
+      "--save" => {
+        options.save = true;
+      },
+      _ => {
+        options.day = Some(parse_day(arg)?);
+      },
+    }
+  }
+
+  Ok(options)
+}
+
+struct GenerateOptions {
+  day: Option<u8>,
+  seed: u64,
+  size: usize,
+  output: Option<String>,
+}
+
+fn parse_generate_options(args: &[String]) -> Result<GenerateOptions, String> {
+  let mut options = GenerateOptions{ day: None, seed: 0, size: 10, output: None };
+
+  let mut args = args.iter();
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "--seed" => {
+        options.seed = match args.next().map(|value| value.parse::<u64>()) {
+          Some(Ok(seed)) => seed,
+          Some(_) => return Err(String::from("invalid value for \"--seed\", expected a number")),
+          None => return Err(String::from("missing value for \"--seed\"")),
+        };
+      },
+      "--size" => {
+        options.size = match args.next().map(|value| value.parse::<usize>()) {
+          Some(Ok(size)) if size > 0 => size,
+          Some(_) => return Err(String::from("invalid value for \"--size\", expected a positive number")),
+          None => return Err(String::from("missing value for \"--size\"")),
+        };
+      },
+      "--output" => {
+        options.output = match args.next() {
+          Some(value) => Some(value.clone()),
+          None => return Err("missing value for \"--output\"".into()),
+        };
+      },
+      _ => {
+        options.day = Some(parse_day(arg)?);
+      },
+    }
+  }
+
+  Ok(options)
+}
+
+/// Paths are resolved against the workspace root so the runner works from any directory.
+fn workspace_path(relative: &str) -> String {
+  match Path::new(env!("CARGO_MANIFEST_DIR")).parent() {
+    Some(root) => root.join(relative).display().to_string(),
+    None => relative.to_string(),
+  }
+}
+
+fn default_input_path(day: u8) -> String {
+  workspace_path(&format!("day-{day:02}/input.txt"))
+}
+
+fn default_answers_path(day: u8) -> String {
+  workspace_path(&format!("day-{day:02}/answers.txt"))
+}
+
+fn baseline_path() -> String {
+  workspace_path("bench_output.txt")
+}
+
+fn parse_day(arg: &str) -> Result<u8, String> {
+  match arg.parse::<u8>() {
+    Ok(day) if days::find(day).is_some() => Ok(day),
+    _ => Err(format!("invalid day \"{arg}\", expected a number between 1 and {}", days::DAYS.len())),
+  }
+}
+
+/// Why `aoc run <day>` stopped early.
+enum RunError {
+  /// A flag does not suit the day; shown with the usage text.
+  Argument(String),
+  /// The day or its input is missing or unreadable.
+  Solve(Error),
+}
+
+fn run_day(number: u8, part: Option<u8>, input: Option<String>, flags: &[(String, String)]) -> Result<(), RunError> {
+  let day = match days::find(number) {
+    Some(day) => day,
+    None => return Err(RunError::Solve(Error::unsolvable(format!("day {number} is not available")))),
+  };
+
+  let source = InputSource::from_arg_or_default(input.as_deref(), &default_input_path(number));
+
+  let contents = match source.read() {
+    Ok(contents) => contents,
+    Err(error) => return Err(RunError::Solve(error)),
+  };
+
+  let parts = match part {
+    Some(part) => vec![part],
+    None => vec![1, 2],
+  };
+
+  let answers = match (day.solve_configured)(&contents, &parts, flags) {
+    Ok(answers) => answers,
+    Err(error) => return Err(RunError::Argument(error)),
+  };
+
+  for (part, answer) in parts.iter().zip(&answers) {
+    match answer {
+      Ok(answer) => println!("part{part}: {answer}"),
+      Err(error) => println!("part{part}: {error}"),
+    }
+  }
+
+  eprint!("{}", report::diagnostics(answers.iter().filter_map(|answer| answer.as_ref().err()), &contents));
+
+  Ok(())
+}
+
+/// Solves every part of every day in parallel, each on its own thread so that a panic or a
+/// part running past `timeout` is reported without holding up the others. Returns the number of
+/// parts that panicked or timed out; parts that are not implemented are listed as skipped. Timed-out parts keep running in the background, beyond
+/// `jobs`, so the summary says how many were still going at the end.
+fn run_all(jobs: usize, timeout: Duration) -> usize {
+  let mut labels = vec![];
+  let mut tasks: Vec<Task<Result<String, Error>>> = vec![];
+
+  for day in days::DAYS.iter() {
+    let contents = fs::read_to_string(default_input_path(day.number)).ok().map(Arc::<str>::from);
+
+    for part in [1, 2] {
+      let contents = contents.clone();
+      let solve = day.solve;
+
+      labels.push((day.number, part));
+      tasks.push(Box::new(move || {
+        let contents = match contents {
+          Some(contents) => contents,
+          None => return Err(Error::Io(String::from("missing input"))),
+        };
+
+        solve(&contents, &[part]).remove(0).map(|answer| answer.to_string())
+      }));
+    }
+  }
+
+  let default_hook = panic::take_hook();
+  panic::set_hook(Box::new(|_| {}));
+
+  let (outcomes, still_running) = pool::run_all(tasks, jobs, timeout);
+
+  panic::set_hook(default_hook);
+
+  let mut failures = 0;
+  let mut rows = vec![(String::from("day"), String::from("part"), String::from("status"), String::from("elapsed"), String::from("answer"))];
+
+  for ((day, part), (outcome, elapsed)) in labels.into_iter().zip(outcomes) {
+    let (status, answer) = match outcome {
+      Outcome::Finished(Ok(answer)) => ("OK", answer),
+      Outcome::Finished(Err(Error::NotImplemented)) => ("SKIP", Error::NotImplemented.to_string()),
+      Outcome::Finished(Err(error)) => ("ERROR", format!("error: {error}")),
+      Outcome::Panicked(message) => ("PANIC", message),
+      Outcome::TimedOut => ("TIMEOUT", format!("no answer after {timeout:?}")),
+    };
+
+    if status == "PANIC" || status == "TIMEOUT" {
+      failures += 1;
+    }
+
+    rows.push((format!("{day:02}"), part.to_string(), status.to_string(), format!("{elapsed:.2?}"), answer));
+  }
+
+  let day_width = rows.iter().map(|row| row.0.len()).max().unwrap_or(0);
+  let part_width = rows.iter().map(|row| row.1.len()).max().unwrap_or(0);
+  let status_width = rows.iter().map(|row| row.2.len()).max().unwrap_or(0);
+  let elapsed_width = rows.iter().map(|row| row.3.len()).max().unwrap_or(0);
+
+  for (index, (day, part, status, elapsed, answer)) in rows.iter().enumerate() {
+    println!("{day:<day_width$} | {part:<part_width$} | {status:<status_width$} | {elapsed:>elapsed_width$} | {answer}");
+
+    if index == 0 {
+      println!("{}-+-{}-+-{}-+-{}-+-{}", "-".repeat(day_width), "-".repeat(part_width), "-".repeat(status_width), "-".repeat(elapsed_width), "-".repeat(6));
+    }
+  }
+
+  if still_running > 0 {
+    eprintln!("{still_running} timed-out part(s) still running in the background, which may have slowed the parts after them");
+  }
+
+  failures
+}
+
+/// Re-solves the selected days and compares every part against `day-NN/answers.txt`.
+/// Returns the number of parts that did not match; parts that are not implemented are skipped.
+fn verify(day: Option<u8>) -> usize {
+  let mut failures = 0;
+
+  for day in days::DAYS.iter().filter(|candidate| day.is_none() || day == Some(candidate.number)) {
+    let answers = match fs::read_to_string(default_answers_path(day.number)) {
+      Ok(contents) => answers::parse_answers(&contents),
+      Err(_) => Err(Error::Io(String::from("no answers file"))),
+    };
+
+    let actual = match fs::read_to_string(default_input_path(day.number)) {
+      Ok(contents) => (day.solve)(&contents, &[1, 2]),
+      Err(_) => vec![Err(Error::Io(String::from("missing input"))), Err(Error::Io(String::from("missing input")))],
+    };
+
+    for (part, actual) in [1, 2].into_iter().zip(actual) {
+      if actual == Err(Error::NotImplemented) {
+        println!("{:02} part{part} SKIP    not implemented", day.number);
+        continue;
+      }
+
+      let actual = match actual {
+        Ok(answer) => answer.to_string(),
+        Err(error) => format!("error: {error}"),
+      };
+
+      match answers.as_ref().map(|answers| answers.get(part)) {
+        Ok(Some(expected)) if expected == actual => {
+          println!("{:02} part{part} PASS    {actual}", day.number);
+        },
+        Ok(Some(expected)) => {
+          println!("{:02} part{part} FAIL    expected {expected}, got {actual}", day.number);
+          failures += 1;
+        },
+        Ok(None) => {
+          println!("{:02} part{part} MISSING got {actual}", day.number);
+        },
+        Err(error) => {
+          println!("{:02} part{part} MISSING got {actual} ({error})", day.number);
+        },
+      }
+    }
+  }
+
+  failures
+}
+
+/// Writes the input generated for `day` from `seed` to `output`, or to stdout without one.
+fn generate(day: u8, seed: u64, size: usize, output: Option<String>) -> Result<(), Error> {
+  let day = match days::find(day) {
+    Some(day) => day,
+    None => return Err(Error::unsolvable(format!("day {day} is not available"))),
+  };
+
+  let contents = (day.generate)(&mut Rng::new(seed), size);
+
+  match output {
+    Some(path) => match fs::write(&path, contents) {
+      Ok(()) => Ok(()),
+      Err(error) => Err(Error::Io(format!("unable to write \"{path}\": {error}"))),
+    },
+    None => {
+      print!("{contents}");
+      Ok(())
+    },
+  }
+}
+
+/// Benchmarks the selected days and compares medians against the baseline file.
+/// Returns the number of stages that regressed beyond the threshold.
+fn run_bench(options: &BenchOptions) -> Result<usize, Error> {
+  let baseline_path = baseline_path();
+
+  let mut baseline = match fs::read_to_string(&baseline_path) {
+    Ok(contents) => Some(bench::parse_baseline(&contents)?),
+    Err(_) => None,
+  };
+
+  let mut measured = bench::Baseline::new();
+  let mut regressions = 0;
+
+  println!("{:<3} {:<5} {:>12} {:>12} {:>12}  baseline", "day", "stage", "min", "median", "max");
+
+  for day in days::DAYS.iter().filter(|candidate| options.day.is_none() || options.day == Some(candidate.number)) {
+    let contents = match fs::read_to_string(default_input_path(day.number)) {
+      Ok(contents) => contents,
+      Err(_) => {
+        println!("{:02}  missing input", day.number);
+        continue;
+      },
+    };
+
+    let timings = match (day.bench)(&contents, options.runs) {
+      Ok(timings) => timings,
+      Err(error) => {
+        println!("{:02}  error: {error}", day.number);
+        continue;
+      },
+    };
+
+    for (stage, timings) in bench::STAGES.iter().zip(timings) {
+      let timings = match timings {
+        Some(timings) => timings,
+        None => {
+          println!("{:02}  {stage:<5} {:>12}", day.number, "error");
+          continue;
+        },
+      };
+
+      let median = timings.median.as_nanos();
+      let key = (day.number, stage.to_string());
+
+      let comparison = match baseline.as_ref().and_then(|baseline| baseline.get(&key)) {
+        Some(&previous) => {
+          let change = bench::change_percent(previous, median);
+          if change > options.threshold {
+            regressions += 1;
+            format!("{change:+.1}% SLOWER")
+          } else {
+            format!("{change:+.1}%")
+          }
+        },
+        None => String::from("-"),
+      };
+
+      println!(
+        "{:02}  {stage:<5} {:>12} {:>12} {:>12}  {comparison}",
+        day.number,
+        format!("{:.2?}", timings.min),
+        format!("{:.2?}", timings.median),
+        format!("{:.2?}", timings.max),
+      );
+
+      measured.insert(key, median);
+    }
+  }
+
+  if options.save || baseline.is_none() {
+    let mut updated = baseline.take().unwrap_or_default();
+    updated.extend(measured);
+
+    if let Err(error) = fs::write(&baseline_path, bench::format_baseline(&updated)) {
+      return Err(Error::Io(format!("unable to write \"{baseline_path}\": {error}")));
+    }
+
+    println!("baseline written to {baseline_path}");
+  }
+
+  Ok(regressions)
+}
+
+fn main() {
+  let args = env::args().skip(1).collect::<Vec<String>>();
+
+  let result = match args.first().map(|arg| arg.as_str()) {
+    Some("run") => {
+      match parse_run_options(&args[1..]) {
+        Ok(options) if options.all => {
+          let jobs = options.jobs.unwrap_or_else(|| thread::available_parallelism().map(|jobs| jobs.get()).unwrap_or(1));
+          let failures = run_all(jobs, options.timeout.unwrap_or(Duration::from_secs(30)));
+
+          if failures > 0 {
+            eprintln!("{failures} part(s) panicked or timed out");
+            process::exit(1);
+          }
+
+          Ok(())
+        },
+        Ok(RunOptions{ day: Some(day), part, input, flags, .. }) => {
+          match run_day(day, part, input, &flags) {
+            Ok(()) => Ok(()),
+            Err(RunError::Solve(error)) => {
+              eprintln!("error: {error}");
+              process::exit(1);
+            },
+            Err(RunError::Argument(error)) => Err(error),
+          }
+        },
+        Ok(_) => Err(String::from("missing day")),
+        Err(error) => Err(error),
+      }
+    },
+    Some("verify") => {
+      let day = match args.get(1..) {
+        Some([]) | None => Ok(None),
+        Some([day]) => parse_day(day).map(Some),
+        Some(_) => Err(String::from("too many arguments")),
+      };
+
+      match day {
+        Ok(day) => {
+          let failures = verify(day);
+          if failures > 0 {
+            eprintln!("{failures} answer(s) did not match");
+            process::exit(1);
+          }
+
+          Ok(())
+        },
+        Err(error) => Err(error),
+      }
+    },
+    Some("bench") => {
+      match parse_bench_options(&args[1..]) {
+        Ok(options) => {
+          match run_bench(&options) {
+            Ok(0) => Ok(()),
+            Ok(regressions) => {
+              eprintln!("{regressions} stage(s) more than {}% slower than the baseline", options.threshold);
+              process::exit(1);
+            },
+            Err(error) => {
+              eprintln!("error: {error}");
+              process::exit(1);
+            },
+          }
+        },
+        Err(error) => Err(error),
+      }
+    },
+    Some("generate") => {
+      match parse_generate_options(&args[1..]) {
+        Ok(GenerateOptions{ day: Some(day), seed, size, output }) => {
+          if let Err(error) = generate(day, seed, size, output) {
+            eprintln!("error: {error}");
+            process::exit(1);
+          }
+
+          Ok(())
+        },
+        Ok(_) => Err(String::from("missing day")),
+        Err(error) => Err(error),
+      }
+    },
+    _ => Err(String::from("unknown command")),
+  };
+
+  if let Err(error) = result {
+    eprintln!("error: {error}");
+    eprintln!("{USAGE}");
+    process::exit(1);
+  }
+}
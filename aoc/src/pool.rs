@@ -0,0 +1,129 @@
+use std::{
+  any::Any,
+  panic::{self, AssertUnwindSafe},
+  sync::{Arc, Mutex, atomic::{AtomicUsize, Ordering}, mpsc},
+  thread,
+  time::{Duration, Instant},
+};
+
+pub enum Outcome<T> {
+  Finished(T),
+  Panicked(String),
+  TimedOut,
+}
+
+pub type Task<T> = Box<dyn FnOnce() -> T + Send>;
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+  match payload.downcast::<String>() {
+    Ok(message) => *message,
+    Err(payload) => match payload.downcast::<&str>() {
+      Ok(message) => message.to_string(),
+      Err(_) => String::from("unknown panic"),
+    },
+  }
+}
+
+/// Runs one task on its own thread, waiting at most `timeout` for it. A task that times out is
+/// left running in the background, as threads cannot be stopped from outside; `running` counts
+/// the task threads that have not finished yet.
+fn run_isolated<T: Send + 'static>(task: Task<T>, timeout: Duration, running: &Arc<AtomicUsize>) -> (Outcome<T>, Duration) {
+  let (sender, receiver) = mpsc::channel();
+  let start = Instant::now();
+
+  running.fetch_add(1, Ordering::SeqCst);
+  let running = Arc::clone(running);
+
+  thread::spawn(move || {
+    let outcome = match panic::catch_unwind(AssertUnwindSafe(task)) {
+      Ok(value) => Outcome::Finished(value),
+      Err(payload) => Outcome::Panicked(panic_message(payload)),
+    };
+
+    running.fetch_sub(1, Ordering::SeqCst);
+    let _ = sender.send(outcome);
+  });
+
+  match receiver.recv_timeout(timeout) {
+    Ok(outcome) => (outcome, start.elapsed()),
+    Err(_) => (Outcome::TimedOut, start.elapsed()),
+  }
+}
+
+/// Runs the tasks on `workers` threads and returns their outcomes and durations in task order,
+/// along with how many timed-out tasks are still running and so still taking up a core. A panic
+/// or timeout only affects its own task.
+pub fn run_all<T: Send + 'static>(tasks: Vec<Task<T>>, workers: usize, timeout: Duration) -> (Vec<(Outcome<T>, Duration)>, usize) {
+  let count = tasks.len();
+  let queue = Arc::new(Mutex::new(tasks.into_iter().enumerate()));
+  let running = Arc::new(AtomicUsize::new(0));
+  let (sender, receiver) = mpsc::channel();
+
+  let handles = (0..workers.clamp(1, count.max(1)))
+    .map(|_| {
+      let queue = Arc::clone(&queue);
+      let running = Arc::clone(&running);
+      let sender = sender.clone();
+
+      thread::spawn(move || {
+        loop {
+          let next = match queue.lock() {
+            Ok(mut queue) => queue.next(),
+            Err(_) => None,
+          };
+
+          let (index, task) = match next {
+            Some(next) => next,
+            None => break,
+          };
+
+          let _ = sender.send((index, run_isolated(task, timeout, &running)));
+        }
+      })
+    })
+    .collect::<Vec<_>>();
+
+  drop(sender);
+
+  let mut results = receiver.iter().collect::<Vec<_>>();
+  results.sort_by_key(|(index, _)| *index);
+
+  for handle in handles {
+    let _ = handle.join();
+  }
+
+  debug_assert_eq!(results.len(), count);
+
+  (results.into_iter().map(|(_, result)| result).collect(), running.load(Ordering::SeqCst))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn isolates_panics_and_timeouts() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let tasks: Vec<Task<u32>> = vec![
+      Box::new(|| 1),
+      Box::new(|| panic!("boom")),
+      Box::new(|| {
+        thread::sleep(Duration::from_secs(5));
+        3
+      }),
+      Box::new(|| 4),
+    ];
+
+    let (outcomes, still_running) = run_all(tasks, 2, Duration::from_millis(200));
+
+    panic::set_hook(default_hook);
+
+    assert!(matches!(outcomes[0].0, Outcome::Finished(1)));
+    assert!(matches!(&outcomes[1].0, Outcome::Panicked(message) if message == "boom"));
+    assert!(matches!(outcomes[2].0, Outcome::TimedOut));
+    assert!(matches!(outcomes[3].0, Outcome::Finished(4)));
+    assert_eq!(still_running, 1);
+  }
+}
@@ -0,0 +1,163 @@
+use aoc_common::{Answer, Error, Solution, random::Rng};
+
+fn sum_calibration_values(lines: &[&str]) -> u64 {
+  lines.iter()
+    .map(|line| {
+      let mut first = 0;
+      let mut last = 0;
+
+      let mut chars = line.chars();
+
+      for char in chars.by_ref() {
+        if let Some(digit) = char.to_digit(10) {
+          first = digit;
+          last = first;
+          break;
+        }
+      }
+
+      for char in chars {
+        if let Some(digit) = char.to_digit(10) {
+          last = digit;
+        }
+      }
+
+      first * 10 + last
+    })
+    .map(u64::from)
+    .sum::<u64>()
+}
+
+fn sum_spelled_calibration_values(lines: &[&str]) -> u64 {
+  let spelled_numbers = vec![
+    (0u32, "0"), (1, "1"), (2, "2"), (3, "3"), (4, "4"), (5, "5"), (6, "6"), (7, "7"), (8, "8"), (9, "9"),
+    (1, "one"), (2, "two"), (3, "three"), (4, "four"), (5, "five"), (6, "six"), (7, "seven"), (8, "eight"), (9, "nine"),
+  ];
+
+  lines.iter()
+    .map(|line| {
+      let first_number = spelled_numbers
+        .iter()
+        .filter_map(|&(value, pattern)| line.find(pattern).map(|pos| (value, pos)))
+        .reduce(|acc, tuple| {
+          if acc.1 > tuple.1 {
+            tuple
+          } else {
+            acc
+          }
+        });
+
+      let last_number = spelled_numbers
+        .iter()
+        .filter_map(|&(value, pattern)| line.rfind(pattern).map(|pos| (value, pos)))
+        .reduce(|acc, tuple| {
+          if acc.1 < tuple.1 {
+            tuple
+          } else {
+            acc
+          }
+        });
+
+      match first_number.zip(last_number) {
+        Some(((first, _), (last, _))) => first * 10 + last,
+        None => 0,
+      }
+    })
+    .map(u64::from)
+    .sum::<u64>()
+}
+
+/// `size` lines of letters, digits and spelled-out digits, each with at least one digit.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  const WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+  let mut contents = String::new();
+
+  for _ in 0..size {
+    let mut line = String::new();
+
+    for _ in 0..rng.range(1..6) {
+      match rng.below(3) {
+        0 => line.push_str(WORDS[rng.below(WORDS.len())]),
+        1 => line.push(char::from(b'a' + rng.below(26) as u8)),
+        _ => line.push(char::from(b'1' + rng.below(9) as u8)),
+      }
+    }
+
+    let digit = rng.below(line.len() + 1);
+    line.insert(digit, char::from(b'1' + rng.below(9) as u8));
+
+    contents.push_str(&line);
+    contents.push('\n');
+  }
+
+  contents
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+  type Input<'a> = Vec<&'a str>;
+
+  type Config = ();
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    Ok(contents.lines().collect())
+  }
+
+  fn part1(lines: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    Ok(sum_calibration_values(lines).into())
+  }
+
+  fn part2(lines: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    Ok(sum_spelled_calibration_values(lines).into())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{fuzz::assert_no_panic, generate::assert_solvable};
+
+  use super::*;
+
+  const EXAMPLE_1: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+";
+
+  const EXAMPLE_2: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+  #[test]
+  fn part1_example() {
+    let input = Day01::parse(EXAMPLE_1).unwrap();
+
+    assert_eq!(Day01::part1(&input, &()), Ok(Answer::Unsigned(142)));
+  }
+
+  #[test]
+  fn part2_example() {
+    let input = Day01::parse(EXAMPLE_2).unwrap();
+
+    assert_eq!(Day01::part2(&input, &()), Ok(Answer::Unsigned(281)));
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day01>(generate, 0..20, 20);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day01>(generate, 0..100, 20);
+  }
+}
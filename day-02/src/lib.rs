@@ -0,0 +1,215 @@
+use std::cmp;
+
+use aoc_common::{Answer, Error, Solution, config::{self, parse_value}, parser::{identifier, map, map_res, number, pair, parse_line, preceded, separated_list1, separated_pair, tag, ws}, random::Rng};
+
+struct CubeCounts {
+  blue: u32,
+  green: u32,
+  red: u32,
+}
+
+pub struct Game {
+  id: u32,
+  sets: Vec<CubeCounts>,
+}
+
+fn parse_game(line_number: usize, line: &str) -> Result<Game, Error> {
+  let color = map_res(identifier, |color| {
+    match color {
+      "blue" | "green" | "red" => Ok(color),
+      _ => Err(format!("unknown color \"{color}\"")),
+    }
+  });
+
+  let cube = pair(ws(number::<u32>()), ws(color));
+
+  let set = map(separated_list1(cube, ws(tag(","))), |cubes| {
+    let mut cube_counts = CubeCounts{ blue: 0, green: 0, red: 0 };
+
+    for (value, color) in cubes {
+      match color {
+        "blue" => cube_counts.blue = cmp::max(cube_counts.blue, value),
+        "green" => cube_counts.green = cmp::max(cube_counts.green, value),
+        _ => cube_counts.red = cmp::max(cube_counts.red, value),
+      }
+    }
+
+    cube_counts
+  });
+
+  let game = separated_pair(
+    preceded(tag("Game"), ws(number::<u32>())),
+    ws(tag(":")),
+    separated_list1(set, ws(tag(";"))),
+  );
+
+  parse_line(line_number, line, map(game, |(id, sets)| Game{ id, sets }))
+}
+
+fn sum_possible_game_ids(games: &[Game], bag: &Config) -> u64 {
+  games
+    .iter()
+    .filter(|game| {
+      game.sets
+        .iter()
+        .all(|cube_counts| cube_counts.blue <= bag.blue && cube_counts.green <= bag.green && cube_counts.red <= bag.red)
+    })
+    .map(|game| u64::from(game.id))
+    .sum::<u64>()
+}
+
+fn sum_minimum_set_powers(games: &[Game]) -> Result<u64, Error> {
+  games
+    .iter()
+    .try_fold(0u64, |sum, game| {
+      let cube_counts = game.sets
+        .iter()
+        .fold(CubeCounts{ blue: 0, green: 0, red: 0 }, |acc, cube_counts| {
+          CubeCounts{
+            blue: cmp::max(acc.blue, cube_counts.blue),
+            green: cmp::max(acc.green, cube_counts.green),
+            red: cmp::max(acc.red, cube_counts.red),
+          }
+        });
+
+      let power = u64::from(cube_counts.blue)
+        .checked_mul(u64::from(cube_counts.green))
+        .and_then(|power| power.checked_mul(u64::from(cube_counts.red)));
+
+      match power.and_then(|power| sum.checked_add(power)) {
+        Some(sum) => Ok(sum),
+        None => Err(Error::unsolvable("the powers of the sets add up to too much")),
+      }
+    })
+}
+
+/// `size` games of one to six draws, each showing up to 20 cubes of one to three colors.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  let mut contents = String::new();
+
+  for id in 1..=size {
+    let draws = (0..rng.range(1..7))
+      .map(|_| {
+        let mut colors = ["red", "green", "blue"];
+        rng.shuffle(&mut colors);
+
+        colors[..rng.range(1..4) as usize]
+          .iter()
+          .map(|color| format!("{} {color}", rng.range(1..21)))
+          .collect::<Vec<String>>()
+          .join(", ")
+      })
+      .collect::<Vec<String>>();
+
+    contents.push_str(&format!("Game {id}: {}\n", draws.join("; ")));
+  }
+
+  contents
+}
+
+/// The cubes in the bag for part 1.
+pub struct Config {
+  pub blue: u32,
+  pub green: u32,
+  pub red: u32,
+}
+
+impl Default for Config {
+  fn default() -> Self {
+    Config{ blue: 14, green: 13, red: 12 }
+  }
+}
+
+impl config::Config for Config {
+  const FLAGS: &'static [(&'static str, &'static str)] = &[("blue", "n"), ("green", "n"), ("red", "n")];
+
+  fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+    let count = parse_value::<u32>(name, value)?;
+
+    match name {
+      "blue" => self.blue = count,
+      "green" => self.green = count,
+      "red" => self.red = count,
+      _ => {},
+    }
+
+    Ok(())
+  }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+  type Input<'a> = Vec<Game>;
+
+  type Config = Config;
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    contents
+      .lines()
+      .enumerate()
+      .map(|(index, line)| parse_game(index + 1, line))
+      .collect()
+  }
+
+  fn part1(games: &Self::Input<'_>, config: &Self::Config) -> Result<Answer, Error> {
+    Ok(sum_possible_game_ids(games, config).into())
+  }
+
+  fn part2(games: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    sum_minimum_set_powers(games).map(Answer::from)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{fuzz::assert_no_panic, generate::assert_solvable};
+
+  use super::*;
+
+  const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+  #[test]
+  fn part1_example() {
+    let input = Day02::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day02::part1(&input, &Config::default()), Ok(Answer::Unsigned(8)));
+  }
+
+  #[test]
+  fn part1_with_a_smaller_bag() {
+    let input = Day02::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day02::part1(&input, &Config{ blue: 6, green: 3, red: 4 }), Ok(Answer::Unsigned(3)));
+  }
+
+  #[test]
+  fn part2_example() {
+    let input = Day02::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day02::part2(&input, &Config::default()), Ok(Answer::Unsigned(2286)));
+  }
+
+  #[test]
+  fn unknown_color_is_a_parse_error() {
+    let result = Day02::parse("Game 1: 3 blue\nGame 2: 3 blue, 4 purple");
+
+    assert_eq!(result.err(), Some(Error::parse(2, 19, "unknown color \"purple\"").spanning(6)));
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day02>(generate, 0..20, 20);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day02>(generate, 0..100, 20);
+  }
+}
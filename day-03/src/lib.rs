@@ -0,0 +1,191 @@
+use aoc_common::{Answer, Error, Grid, Solution, random::Rng};
+
+#[derive(Copy, Clone)]
+struct NumberPositioning {
+  value: u32,
+  row: usize,
+  start: usize,
+  end: usize,
+}
+
+fn is_symbol(cell: char) -> bool {
+  !cell.is_ascii_digit() && cell != '.'
+}
+
+fn extract_all_numbers(grid: &Grid<char>) -> Result<Vec<NumberPositioning>, Error> {
+  let mut result = Vec::new();
+
+  for (row, cells) in grid.iter_rows().enumerate() {
+    let mut col = 0;
+
+    while col < cells.len() {
+      if !cells[col].is_ascii_digit() {
+        col += 1;
+        continue;
+      }
+
+      let start = col;
+      let mut value = 0u32;
+
+      while col < cells.len() && cells[col].is_ascii_digit() {
+        value = match value.checked_mul(10).and_then(|value| value.checked_add(cells[col] as u32 - '0' as u32)) {
+          Some(value) => value,
+          None => {
+            let length = cells[start..].iter().take_while(|cell| cell.is_ascii_digit()).count();
+
+            return Err(Error::parse(row + 1, start + 1, "number is too large").spanning(length));
+          },
+        };
+
+        col += 1;
+      }
+
+      result.push(NumberPositioning{ value, row, start, end: col });
+    }
+  }
+
+  Ok(result)
+}
+
+fn is_adjacent(number_positioning: &NumberPositioning, position: (usize, usize)) -> bool {
+  number_positioning.row.abs_diff(position.0) <= 1
+    && number_positioning.start <= position.1 + 1
+    && position.1 <= number_positioning.end
+}
+
+pub struct Schematic {
+  grid: Grid<char>,
+  numbers: Vec<NumberPositioning>,
+}
+
+fn sum_part_numbers(schematic: &Schematic) -> u64 {
+  let grid = &schematic.grid;
+
+  schematic.numbers
+    .iter()
+    .filter(|number_positioning| {
+      (number_positioning.start..number_positioning.end)
+        .any(|col| grid.neighbors8((number_positioning.row, col)).any(|position| is_symbol(grid[position])))
+    })
+    .map(|number_positioning| u64::from(number_positioning.value))
+    .sum::<u64>()
+}
+
+fn sum_gear_ratios(schematic: &Schematic) -> Result<u64, Error> {
+  let grid = &schematic.grid;
+
+  grid.positions()
+    .filter(|&position| grid[position] == '*')
+    .map(|position| {
+      let adjacent_numbers = schematic.numbers
+        .iter()
+        .filter(|number_positioning| is_adjacent(number_positioning, position))
+        .collect::<Vec<&NumberPositioning>>();
+
+      if adjacent_numbers.len() != 2 {
+        return 0;
+      }
+
+      adjacent_numbers
+        .iter()
+        .fold(1, |acc, number_positioning| acc * u64::from(number_positioning.value))
+    })
+    .try_fold(0u64, |sum, ratio| sum.checked_add(ratio))
+    .ok_or_else(|| Error::unsolvable("the gear ratios add up to too much"))
+}
+
+/// A `size` by `size` schematic of numbers of up to three digits scattered among symbols.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '=', '%', '@', '&', '-'];
+
+  let mut contents = String::new();
+
+  for _ in 0..size {
+    let mut row = String::new();
+
+    while row.len() < size {
+      let width = rng.range(1..4) as usize;
+
+      if !row.ends_with(|char: char| char.is_ascii_digit()) && row.len() + width <= size && rng.chance(1, 4) {
+        row.push_str(&rng.range(10i64.pow(width as u32 - 1)..10i64.pow(width as u32)).to_string());
+      } else if rng.chance(1, 8) {
+        row.push(*rng.choose(&SYMBOLS));
+      } else {
+        row.push('.');
+      }
+    }
+
+    contents.push_str(&row);
+    contents.push('\n');
+  }
+
+  contents
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+  type Input<'a> = Schematic;
+
+  type Config = ();
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    let grid = Grid::parse(contents)?;
+
+    let numbers = extract_all_numbers(&grid)?;
+
+    Ok(Schematic{ grid, numbers })
+  }
+
+  fn part1(schematic: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    Ok(sum_part_numbers(schematic).into())
+  }
+
+  fn part2(schematic: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    sum_gear_ratios(schematic).map(Answer::from)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{fuzz::assert_no_panic, generate::assert_solvable};
+
+  use super::*;
+
+  const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+  #[test]
+  fn part1_example() {
+    let input = Day03::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day03::part1(&input, &()), Ok(Answer::Unsigned(4361)));
+  }
+
+  #[test]
+  fn part2_example() {
+    let input = Day03::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day03::part2(&input, &()), Ok(Answer::Unsigned(467835)));
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day03>(generate, 0..20, 20);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day03>(generate, 0..100, 20);
+  }
+}
@@ -0,0 +1,177 @@
+use aoc_common::{Answer, Error, Solution, extract::separated_integers, parser::{and_then, map, number, pair, parse_line, preceded, rest, separated_pair, tag, take_while, ws}, random::Rng};
+
+fn parse_scratchcard(line_number: usize, line: &str) -> Result<Scratchcard, Error> {
+  let scratchcard = preceded(
+    pair(tag("Card"), ws(number::<u32>())),
+    preceded(
+      ws(tag(":")),
+      separated_pair(and_then(take_while(|char| char != '|'), separated_integers), tag("|"), and_then(rest, separated_integers)),
+    ),
+  );
+
+  parse_line(line_number, line, map(scratchcard, |(winning_numbers, chosen_numbers)| Scratchcard{ winning_numbers, chosen_numbers }))
+}
+
+pub struct Scratchcard {
+  winning_numbers: Vec<u32>,
+  chosen_numbers: Vec<u32>,
+}
+
+fn count_matching_numbers(scratchcard: &Scratchcard) -> usize {
+  scratchcard.chosen_numbers
+    .iter()
+    .fold(0usize, |acc, chosen_number| {
+      if scratchcard.winning_numbers.iter().any(|winning_number| winning_number == chosen_number) {
+        acc + 1
+      } else {
+        acc
+      }
+    })
+}
+
+fn sum_points(scratchcards: &[Scratchcard]) -> Result<u64, Error> {
+  scratchcards
+    .iter()
+    .try_fold(0u64, |total, scratchcard| {
+      let points = match count_matching_numbers(scratchcard) {
+        0 => 0,
+        matches if matches <= 64 => 1u64 << (matches - 1),
+        _ => return Err(Error::unsolvable("a card is worth too many points")),
+      };
+
+      match total.checked_add(points) {
+        Some(total) => Ok(total),
+        None => Err(Error::unsolvable("the points add up to too many")),
+      }
+    })
+}
+
+fn count_scratchcards(scratchcards: &[Scratchcard]) -> Result<u64, Error> {
+  let winning_counts = scratchcards
+    .iter()
+    .map(count_matching_numbers)
+    .collect::<Vec<usize>>();
+
+  let mut scratchcard_counts = Vec::<u64>::new();
+  scratchcard_counts.resize(winning_counts.len(), 1);
+
+  let mut i = 0usize;
+  while i < scratchcard_counts.len() {
+    if i + winning_counts[i] >= scratchcard_counts.len() {
+      return Err(Error::unsolvable(format!("card {} wins copies of cards past the end of the table", i + 1)));
+    }
+
+    let mut j = 0usize;
+    while j < winning_counts[i] {
+      scratchcard_counts[i + j + 1] = match scratchcard_counts[i + j + 1].checked_add(scratchcard_counts[i]) {
+        Some(count) => count,
+        None => return Err(Error::unsolvable("too many scratchcards to count")),
+      };
+
+      j += 1;
+    }
+
+    i += 1;
+  }
+
+  match scratchcard_counts.iter().try_fold(0u64, |total, &count| total.checked_add(count)) {
+    Some(total) => Ok(total),
+    None => Err(Error::unsolvable("too many scratchcards to count")),
+  }
+}
+
+/// `size` cards of five winning numbers and eight chosen ones. No card wins copies of cards past
+/// the end of the table.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  let mut contents = String::new();
+
+  for id in 1..=size {
+    let mut numbers = (1..100).collect::<Vec<u32>>();
+    rng.shuffle(&mut numbers);
+
+    let matches = rng.below(5.min(size - id) + 1);
+
+    let winning_numbers = &numbers[..5];
+    let mut chosen_numbers = numbers[..matches].iter().chain(&numbers[5..13 - matches]).copied().collect::<Vec<u32>>();
+    rng.shuffle(&mut chosen_numbers);
+
+    let format = |numbers: &[u32]| numbers.iter().map(|number| format!("{number:>2}")).collect::<Vec<String>>().join(" ");
+
+    contents.push_str(&format!("Card {id:>3}: {} | {}\n", format(winning_numbers), format(&chosen_numbers)));
+  }
+
+  contents
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+  type Input<'a> = Vec<Scratchcard>;
+
+  type Config = ();
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    contents
+      .lines()
+      .enumerate()
+      .map(|(index, line)| parse_scratchcard(index + 1, line))
+      .collect()
+  }
+
+  fn part1(scratchcards: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    sum_points(scratchcards).map(Answer::from)
+  }
+
+  fn part2(scratchcards: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    count_scratchcards(scratchcards).map(Answer::from)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{fuzz::assert_no_panic, generate::assert_solvable};
+
+  use super::*;
+
+  const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+  #[test]
+  fn part1_example() {
+    let input = Day04::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day04::part1(&input, &()), Ok(Answer::Unsigned(13)));
+  }
+
+  #[test]
+  fn part2_example() {
+    let input = Day04::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day04::part2(&input, &()), Ok(Answer::Unsigned(30)));
+  }
+
+  #[test]
+  fn stray_characters_between_numbers_are_refused() {
+    let result = Day04::parse("Card 1: 41 48 8a3 | 83 86");
+    assert_eq!(result.err(), Some(Error::parse(1, 16, "expected whitespace or \",\" after a number").spanning(2)));
+
+    let result = Day04::parse("Card 1: 41 48 | 83 86 x");
+    assert_eq!(result.err(), Some(Error::parse(1, 23, "expected a number").spanning(1)));
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day04>(generate, 0..20, 20);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day04>(generate, 0..100, 20);
+  }
+}
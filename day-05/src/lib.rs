@@ -0,0 +1,300 @@
+use aoc_common::{Answer, Error, Solution, extract::separated_integers, interval::{Interval, union_all}, random::Rng};
+
+fn extract_seeds(contents: &str) -> Result<Vec<i64>, Error> {
+  let line = match contents.lines().next() {
+    Some(line) => line,
+    None => return Err(Error::parse(1, 0, "missing seeds")),
+  };
+
+  let seeds = match line.trim_start().strip_prefix("seeds: ") {
+    Some(seeds) => seeds,
+    None => return Err(Error::parse(1, 1, "missing \"seeds: \" prefix")),
+  };
+
+  extract_numbers(1, line, seeds)
+}
+
+/// The integers in `text`, a slice of `line`. They must be non-negative and fit in an `i64`.
+fn extract_numbers(line_number: usize, line: &str, text: &str) -> Result<Vec<i64>, Error> {
+  let numbers = match separated_integers::<i64>(text) {
+    Ok(numbers) => numbers,
+    Err(failure) => return Err(failure.into_error(line_number, line)),
+  };
+
+  match text.find('-') {
+    Some(sign) => {
+      let length = 1 + text[sign + 1..].bytes().take_while(u8::is_ascii_digit).count();
+
+      Err(Error::parse_at(line_number, line, &text[sign..sign + length], "expected a non-negative number"))
+    },
+    None => Ok(numbers),
+  }
+}
+
+/// Splits the lines into blank-line separated blocks, each paired with the index of its first line.
+fn split_into_blocks<'a>(lines: &'a [&'a str]) -> Vec<(usize, &'a [&'a str])> {
+  let mut blocks = Vec::new();
+
+  let mut start_index = 0;
+
+  for (index, line) in lines.iter().enumerate() {
+    if line.is_empty() {
+      if !lines[start_index].starts_with("seeds:") {
+        blocks.push((start_index, &lines[start_index..index]));
+      }
+
+      start_index = index + 1;
+    }
+  }
+
+  if start_index < lines.len()
+    && start_index != 0 {
+      blocks.push((start_index, &lines[start_index..lines.len()]));
+    }
+
+  blocks
+}
+
+/// Moves every value in `source` by `offset`.
+struct Transform {
+  source: Interval,
+  offset: i64,
+}
+
+fn extract_mappings(contents: &str) -> Result<Vec<Vec<Transform>>, Error> {
+  let lines = contents.lines().collect::<Vec<&str>>();
+
+  split_into_blocks(&lines[..])
+    .iter()
+    .map(|&(start_index, block)| {
+      block
+        .iter()
+        .skip(1)
+        .enumerate()
+        .map(|(index, line)| {
+          let line_number = start_index + index + 2;
+
+          let numbers = match extract_numbers(line_number, line, line) {
+            Ok(numbers) => numbers,
+            Err(error) => return Err(error),
+          };
+
+          let [destination, source, length] = match numbers[..] {
+            [destination, source, length] => [destination, source, length],
+            _ => return Err(Error::parse(line_number, 0, "expected destination, source and length")),
+          };
+
+          Ok(Transform{
+            source: Interval::with_length(source, length as u64),
+            offset: destination - source,
+          })
+        })
+        .collect()
+    })
+    .collect()
+}
+
+/// Maps every interval through the first transform covering each of its parts; parts that no
+/// transform covers keep their values.
+fn apply_transforms(intervals: Vec<Interval>, transforms: &[Transform]) -> Vec<Interval> {
+  let mut mapped = vec![];
+  let mut unmapped = intervals;
+
+  for transform in transforms {
+    let mut remaining = vec![];
+
+    for interval in unmapped {
+      if let Some(common) = interval.intersection(&transform.source) {
+        mapped.push(common.shift(transform.offset));
+      }
+
+      remaining.extend(interval.subtract(&transform.source));
+    }
+
+    unmapped = remaining;
+  }
+
+  mapped.extend(unmapped);
+
+  union_all(mapped)
+}
+
+pub struct Almanac {
+  seeds: Vec<i64>,
+  mappings: Vec<Vec<Transform>>,
+}
+
+fn find_lowest_location(seeds: Vec<Interval>, mappings: &[Vec<Transform>]) -> u64 {
+  let locations = mappings
+    .iter()
+    .fold(union_all(seeds), |intervals, transforms| apply_transforms(intervals, transforms));
+
+  match locations.first() {
+    Some(interval) => interval.start as u64,
+    None => u64::MAX,
+  }
+}
+
+/// An almanac of `size` seed ranges and seven maps of up to `size` ranges each. Every map moves
+/// whole blocks of `0..2^32` around, so its ranges never overlap.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  const LIMIT: i64 = 1 << 32;
+  const NAMES: [&str; 8] = ["seed", "soil", "fertilizer", "water", "light", "temperature", "humidity", "location"];
+
+  let seeds = (0..size)
+    .flat_map(|_| {
+      let start = rng.range(0..LIMIT);
+
+      [start, rng.range(1..(LIMIT - start).min(LIMIT / size.max(1) as i64) + 1)]
+    })
+    .map(|number| number.to_string())
+    .collect::<Vec<String>>();
+
+  let mut contents = format!("seeds: {}\n", seeds.join(" "));
+
+  for names in NAMES.windows(2) {
+    let mut cuts = (0..size).map(|_| rng.range(1..LIMIT)).chain([0, LIMIT]).collect::<Vec<i64>>();
+    cuts.sort();
+    cuts.dedup();
+
+    let mut blocks = cuts.windows(2).map(|window| (window[0], window[1] - window[0])).collect::<Vec<(i64, i64)>>();
+    rng.shuffle(&mut blocks);
+
+    let mut destination = 0;
+    let mut lines = vec![];
+
+    for (source, length) in blocks {
+      if lines.is_empty() || rng.chance(3, 4) {
+        lines.push(format!("{destination} {source} {length}"));
+      }
+
+      destination += length;
+    }
+
+    rng.shuffle(&mut lines);
+
+    contents.push_str(&format!("\n{}-to-{} map:\n{}\n", names[0], names[1], lines.join("\n")));
+  }
+
+  contents
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+  type Input<'a> = Almanac;
+
+  type Config = ();
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    let seeds = extract_seeds(contents)?;
+
+    let mappings = extract_mappings(contents)?;
+
+    Ok(Almanac{ seeds, mappings })
+  }
+
+  fn part1(almanac: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    let seeds = almanac.seeds
+      .iter()
+      .map(|&seed| Interval::with_length(seed, 1))
+      .collect();
+
+    Ok(find_lowest_location(seeds, &almanac.mappings).into())
+  }
+
+  fn part2(almanac: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    let seeds = almanac.seeds
+      .chunks_exact(2)
+      .map(|pair| Interval::with_length(pair[0], pair[1] as u64))
+      .collect();
+
+    Ok(find_lowest_location(seeds, &almanac.mappings).into())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{fuzz::assert_no_panic, generate::assert_solvable, solution::solve};
+
+  use super::*;
+
+  const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+  #[test]
+  fn part1_example() {
+    let input = Day05::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day05::part1(&input, &()), Ok(Answer::Unsigned(35)));
+  }
+
+  #[test]
+  fn part2_example() {
+    let input = Day05::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day05::part2(&input, &()), Ok(Answer::Unsigned(46)));
+  }
+
+  #[test]
+  fn crlf_input_is_normalized() {
+    let contents = EXAMPLE.replace('\n', "\r\n");
+
+    assert_eq!(solve::<Day05>(&contents, &[1, 2]), vec![Ok(Answer::Unsigned(35)), Ok(Answer::Unsigned(46))]);
+  }
+
+  #[test]
+  fn malformed_numbers_are_refused() {
+    let result = Day05::parse(&EXAMPLE.replace("seeds: 79 14", "seeds: 79 x14"));
+    assert_eq!(result.err(), Some(Error::parse(1, 11, "expected a number").spanning(3)));
+
+    let result = Day05::parse(&EXAMPLE.replace("seeds: 79 14", "seeds: 79 -14"));
+    assert_eq!(result.err(), Some(Error::parse(1, 11, "expected a non-negative number").spanning(3)));
+
+    let result = Day05::parse(&EXAMPLE.replace("52 50 48", "52 50 9223372036854775808"));
+    assert_eq!(result.err(), Some(Error::parse(5, 7, "invalid number \"9223372036854775808\": number too large to fit in target type").spanning(19)));
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day05>(generate, 0..20, 10);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day05>(generate, 0..100, 10);
+  }
+}
@@ -0,0 +1,202 @@
+use aoc_common::{Answer, Error, Solution, extract::{kerned_integers, separated_integers}, random::Rng};
+
+/// The numbers after the colon; with `kerning` the spaces between digits are ignored.
+fn extract_values(line_number: usize, line: &str, kerning: bool) -> Result<Vec<u64>, Error> {
+  let colon_pos = match line.find(':') {
+    Some(colon_pos) => colon_pos,
+    None => return Err(Error::parse(line_number, 0, "missing \":\"")),
+  };
+
+  let values = &line[(colon_pos + ":".len())..];
+
+  let extracted = match separated_integers(values) {
+    Ok(_) if kerning => kerned_integers(values),
+    extracted => extracted,
+  };
+
+  extracted.map_err(|failure| failure.into_error(line_number, line))
+}
+
+fn extract_value_ignoring_kerning(line_number: usize, line: &str) -> Result<u64, Error> {
+  match extract_values(line_number, line, true) {
+    Ok(values) if values.len() == 1 => Ok(values[0]),
+    Ok(_) => Err(Error::parse(line_number, 0, "expected a number after \":\"")),
+    Err(error) => Err(error),
+  }
+}
+
+/// Counts the hold times that beat the record. The distance `hold * (time - hold)` rises until
+/// half the race and falls symmetrically after it, so the shortest winning hold fixes the count.
+fn calculate_margin_of_error(time: u64, record_distance: u64) -> u64 {
+  let distance = |hold: u64| hold as u128 * (time - hold) as u128;
+  let beats_record = |hold: u64| distance(hold) > record_distance as u128;
+
+  if !beats_record(time / 2) {
+    return 0;
+  }
+
+  let mut low = 0;
+  let mut high = time / 2;
+  while low < high {
+    let middle = low + (high - low) / 2;
+
+    if beats_record(middle) {
+      high = middle;
+    } else {
+      low = middle + 1;
+    }
+  }
+
+  time - 2 * low + 1
+}
+
+pub struct RaceDocument {
+  times: Vec<u64>,
+  distances: Vec<u64>,
+  time_ignoring_kerning: u64,
+  distance_ignoring_kerning: u64,
+}
+
+fn multiply_margins_of_error(race_document: &RaceDocument) -> Result<u64, Error> {
+  if race_document.times.is_empty() {
+    return Ok(0);
+  }
+
+  (0..race_document.times.len())
+    .map(|index| {
+      let time = race_document.times[index];
+      let record_distance = race_document.distances[index];
+
+      calculate_margin_of_error(time, record_distance)
+    })
+    .try_fold(1u64, |acc, value| {
+      match acc.checked_mul(value) {
+        Some(product) => Ok(product),
+        None => Err(Error::unsolvable("the margins of error multiply to too many ways")),
+      }
+    })
+}
+
+/// `size` races, at most four, of under a hundred milliseconds. Every record can be beaten, also
+/// when the columns are read as one long race.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  loop {
+    let races = (0..size.clamp(1, 4))
+      .map(|_| {
+        let time = rng.range(7..100) as u64;
+
+        (time, rng.range(0..((time / 2) * (time - time / 2)) as i64) as u64)
+      })
+      .collect::<Vec<(u64, u64)>>();
+
+    let joined = |values: Vec<String>| values.concat().parse::<u64>().unwrap_or(u64::MAX);
+    let time = joined(races.iter().map(|race| race.0.to_string()).collect());
+    let distance = joined(races.iter().map(|race| race.1.to_string()).collect());
+
+    if (time / 2) * (time - time / 2) <= distance {
+      continue;
+    }
+
+    let (times, distances) = races
+      .iter()
+      .map(|&(time, distance)| {
+        let width = distance.to_string().len().max(time.to_string().len()) + 1 + rng.below(3);
+
+        (format!("{time:>width$}"), format!("{distance:>width$}"))
+      })
+      .unzip::<String, String, Vec<String>, Vec<String>>();
+
+    return format!("Time:    {}\nDistance:{}\n", times.concat(), distances.concat());
+  }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+  type Input<'a> = RaceDocument;
+
+  type Config = ();
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    let mut lines = contents.lines();
+
+    let time_line = match lines.next() {
+      Some(line) => line,
+      None => return Err(Error::parse(1, 0, "missing times")),
+    };
+    let distance_line = match lines.next() {
+      Some(line) => line,
+      None => return Err(Error::parse(2, 0, "missing distances")),
+    };
+
+    let times = extract_values(1, time_line, false)?;
+    let distances = extract_values(2, distance_line, false)?;
+
+    if times.len() != distances.len() {
+      return Err(Error::parse(2, 0, format!("expected {} distances, found {}", times.len(), distances.len())));
+    }
+
+    let time_ignoring_kerning = extract_value_ignoring_kerning(1, time_line)?;
+    let distance_ignoring_kerning = extract_value_ignoring_kerning(2, distance_line)?;
+
+    Ok(RaceDocument{ times, distances, time_ignoring_kerning, distance_ignoring_kerning })
+  }
+
+  fn part1(race_document: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    multiply_margins_of_error(race_document).map(Answer::from)
+  }
+
+  fn part2(race_document: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    Ok(calculate_margin_of_error(race_document.time_ignoring_kerning, race_document.distance_ignoring_kerning).into())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{fuzz::assert_no_panic, generate::assert_solvable};
+
+  use super::*;
+
+  const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200
+";
+
+  #[test]
+  fn part1_example() {
+    let input = Day06::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day06::part1(&input, &()), Ok(Answer::Unsigned(288)));
+  }
+
+  #[test]
+  fn part2_example() {
+    let input = Day06::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day06::part2(&input, &()), Ok(Answer::Unsigned(71503)));
+  }
+
+  #[test]
+  fn margins_of_degenerate_races() {
+    assert_eq!(calculate_margin_of_error(0, 0), 0);
+    assert_eq!(calculate_margin_of_error(2, 0), 1);
+    assert_eq!(calculate_margin_of_error(7, 12), 0);
+    assert_eq!(calculate_margin_of_error(u64::MAX, u64::MAX), u64::MAX - 3);
+  }
+
+  #[test]
+  fn stray_characters_between_numbers_are_refused() {
+    let result = Day06::parse("Time:      7  15   3O\nDistance:  9  40  200");
+    assert_eq!(result.err(), Some(Error::parse(1, 21, "expected whitespace or \",\" after a number").spanning(1)));
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day06>(generate, 0..20, 3);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day06>(generate, 0..100, 3);
+  }
+}
@@ -0,0 +1,457 @@
+use std::cmp::Ordering;
+
+use aoc_common::{Answer, Error, Solution, random::Rng};
+
+fn parse_line(line_number: usize, line: &str) -> Result<(&str, u32), Error> {
+  let mut parts = line.split_whitespace();
+
+  let hand = match parts.next() {
+    Some(hand) => hand,
+    None => return Err(Error::parse(line_number, 0, "missing hand")),
+  };
+  let bid = match parts.next() {
+    Some(bid) => bid,
+    None => return Err(Error::parse(line_number, 0, "missing bid")),
+  };
+  let bid = match bid.parse::<u32>() {
+    Ok(bid) => bid,
+    Err(error) => return Err(Error::parse_at(line_number, line, bid, format!("invalid bid \"{bid}\": {error}"))),
+  };
+
+  if let Some((index, card)) = hand.char_indices().find(|&(_, card)| card_strength(card) == 0) {
+    return Err(Error::parse_at(line_number, line, &hand[index..], format!("invalid card \"{card}\"")));
+  }
+  if hand.len() != 5 {
+    return Err(Error::parse_at(line_number, line, hand, format!("expected 5 cards, found {}", hand.len())));
+  }
+
+  Ok((hand, bid))
+}
+
+fn card_strength(card: char) -> u32 {
+  match card {
+    'A' => 14,
+    'K' => 13,
+    'Q' => 12,
+    'J' => 11,
+    'T' => 10,
+    '9' => 9,
+    '8' => 8,
+    '7' => 7,
+    '6' => 6,
+    '5' => 5,
+    '4' => 4,
+    '3' => 3,
+    '2' => 2,
+    _ => 0,
+  }
+}
+
+fn card_strength_with_jokers(card: char) -> u32 {
+  match card {
+    'A' => 14,
+    'K' => 13,
+    'Q' => 12,
+    'T' => 10,
+    '9' => 9,
+    '8' => 8,
+    '7' => 7,
+    '6' => 6,
+    '5' => 5,
+    '4' => 4,
+    '3' => 3,
+    '2' => 2,
+    'J' => 1,
+    _ => 0,
+  }
+}
+
+fn length_of_equal(hand_sorted: &[u32]) -> usize {
+  if hand_sorted.is_empty() {
+    return 0;
+  }
+
+  let mut i = 1;
+  while i < hand_sorted.len() {
+    if hand_sorted[0] != hand_sorted[i] {
+      return i;
+    }
+
+    i += 1;
+  }
+
+  i
+}
+
+fn length_of_jokers(hand_sorted: &[u32; 5]) -> usize {
+  const JOKER: u32 = 1;
+
+  let mut i = 0;
+
+  while i < hand_sorted.len() && hand_sorted[hand_sorted.len() - 1 - i] == JOKER {
+    i += 1;
+  }
+  i
+}
+
+#[allow(clippy::nonminimal_bool)]
+fn hand_strength(hand_sorted: &[u32; 5]) -> u32 {
+  let lengths_of_equal = [
+    length_of_equal(&hand_sorted[0..]),
+    length_of_equal(&hand_sorted[1..]),
+    length_of_equal(&hand_sorted[2..]),
+    length_of_equal(&hand_sorted[3..]),
+    length_of_equal(&hand_sorted[4..]),
+  ];
+
+  /*
+  XXXXX
+  */
+  if lengths_of_equal[0] == 5 {
+    return 6;
+  }
+
+  /*
+  XXXXY
+  YXXXX
+  */
+  if lengths_of_equal[0] == 4 || lengths_of_equal[1] == 4 {
+    return 5;
+  }
+
+  /*
+  XXXYY
+  YYXXX
+  */
+  if (lengths_of_equal[0] == 3 && lengths_of_equal[3] == 2) || (lengths_of_equal[0] == 2 && lengths_of_equal[2] == 3) {
+    return 4;
+  }
+
+  /*
+  XXXYZ
+  YXXXZ
+  YZXXX
+  */
+  if lengths_of_equal[0] == 3 || lengths_of_equal[1] == 3 || lengths_of_equal[2] == 3 {
+    return 3;
+  }
+
+  /*
+  XXYYZ
+  XXZYY
+  ZXXYY
+  */
+  if (lengths_of_equal[0] == 2 && lengths_of_equal[2] == 2) || (lengths_of_equal[0] == 2 && lengths_of_equal[3] == 2) || (lengths_of_equal[1] == 2 && lengths_of_equal[3] == 2) {
+    return 2;
+  }
+
+  /*
+  XXYZW
+  YXXZW
+  YZXXW
+  YZWXX
+  */
+  if lengths_of_equal[0] == 2 || lengths_of_equal[1] == 2 || lengths_of_equal[2] == 2 || lengths_of_equal[3] == 2 {
+    return 1;
+  }
+
+  0
+}
+
+#[allow(clippy::nonminimal_bool)]
+fn hand_strength_with_jokers(hand_sorted: &[u32; 5]) -> u32 {
+  let jokers = length_of_jokers(hand_sorted);
+
+  let lengths_of_equal = [
+    if jokers < 5 { length_of_equal(&hand_sorted[0..]) } else { 0 },
+    if jokers < 4 { length_of_equal(&hand_sorted[1..]) } else { 0 },
+    if jokers < 3 { length_of_equal(&hand_sorted[2..]) } else { 0 },
+    if jokers < 2 { length_of_equal(&hand_sorted[3..]) } else { 0 },
+    if jokers < 1 { length_of_equal(&hand_sorted[4..]) } else { 0 },
+  ];
+
+  /*
+  XXXXX
+
+  XXXXJ
+
+  XXXJJ
+
+  XXJJJ
+
+  XJJJJ
+
+  JJJJJ
+  */
+  if lengths_of_equal[0] + jokers == 5 {
+    return 6;
+  }
+
+  /*
+  XXXXY
+  YXXXX
+
+  XXXYJ
+  YXXXJ
+
+  XXYJJ
+  YXXJJ
+
+  XYJJJ
+  YXJJJ
+
+  YJJJJ
+  */
+  if lengths_of_equal[0] + jokers == 4 || lengths_of_equal[1] + jokers == 4 {
+    return 5;
+  }
+
+  /*
+  XXXYY
+  YYXXX
+
+  XXYYJ
+  YYXXJ
+  */
+  if (lengths_of_equal[0] == 3 && lengths_of_equal[3] == 2) || (lengths_of_equal[0] == 2 && lengths_of_equal[2] == 3) ||
+     (lengths_of_equal[0] == 2 && lengths_of_equal[2] == 2 && jokers == 1) {
+    return 4;
+  }
+
+  /*
+  XXXYZ
+  YXXXZ
+  YZXXX
+
+  XXYZJ
+  YXXZJ
+  YZXXJ
+
+  XYZJJ
+  */
+  if lengths_of_equal[0] + jokers == 3 || lengths_of_equal[1] + jokers == 3 || lengths_of_equal[2] + jokers == 3 {
+    return 3;
+  }
+
+  /*
+  XXYYZ
+  XXZYY
+  ZXXYY
+  */
+  if (lengths_of_equal[0] == 2 && lengths_of_equal[2] == 2) || (lengths_of_equal[0] == 2 && lengths_of_equal[3] == 2) || (lengths_of_equal[1] == 2 && lengths_of_equal[3] == 2) {
+    return 2;
+  }
+
+  /*
+  XXYZW
+  YXXZW
+  YZXXW
+  YZWXX
+
+  XYZWJ
+  */
+  if lengths_of_equal[0] + jokers == 2 || lengths_of_equal[1] + jokers == 2 || lengths_of_equal[2] + jokers == 2 || lengths_of_equal[3] + jokers == 2 {
+    return 1;
+  }
+
+  0
+}
+
+fn resolve_hand(hand: &str) -> ([u32; 5], u32) {
+  let mut chars = hand.chars();
+
+  let mut hand_resolved = [1, 1, 1, 1, 1];
+  let mut hand_sorted = [1, 1, 1, 1, 1];
+  for i in 0..5 {
+    if let Some(card) = chars.next() {
+      hand_resolved[i] = card_strength(card);
+      hand_sorted[i] = hand_resolved[i];
+    } else {
+      break;
+    }
+  }
+
+  hand_sorted.sort_by(|a, b| b.cmp(a));
+
+  (hand_resolved, hand_strength(&hand_sorted))
+}
+
+fn resolve_hand_with_jokers(hand: &str) -> ([u32; 5], u32) {
+  let mut chars = hand.chars();
+
+  let mut hand_resolved = [1, 1, 1, 1, 1];
+  let mut hand_sorted = [1, 1, 1, 1, 1];
+  for i in 0..5 {
+    if let Some(card) = chars.next() {
+      hand_resolved[i] = card_strength_with_jokers(card);
+      hand_sorted[i] = hand_resolved[i];
+    } else {
+      break;
+    }
+  }
+
+  hand_sorted.sort_by(|a, b| b.cmp(a));
+
+  (hand_resolved, hand_strength_with_jokers(&hand_sorted))
+}
+
+fn calculate_total_winnings(hands_and_bids: &[(&str, u32)]) -> Result<u64, Error> {
+  let mut hands_with_strength_and_bids = hands_and_bids.iter()
+    .map(|&(hand, bid)| {
+      let (hand_resolved, hand_strength) = resolve_hand(hand);
+
+      (hand_resolved, hand_strength, bid)
+    })
+    .collect::<Vec<([u32; 5], u32, u32)>>();
+
+  hands_with_strength_and_bids.sort_by(|a, b| {
+    let (a_hand, a_strength, _) = a;
+    let (b_hand, b_strength, _) = b;
+
+    let strength_ord = a_strength.cmp(b_strength);
+    if strength_ord != Ordering::Equal {
+      return strength_ord;
+    }
+
+    for i in 0..5 {
+      let hand_ord = a_hand[i].cmp(&b_hand[i]);
+      if hand_ord != Ordering::Equal {
+        return hand_ord;
+      }
+    }
+
+    Ordering::Equal
+  });
+
+  hands_with_strength_and_bids
+    .iter()
+    .enumerate()
+    .try_fold(0u64, |total, (index, &(_, _, bid))| total.checked_add((index as u64 + 1) * bid as u64))
+    .ok_or_else(|| Error::unsolvable("the winnings add up to too much"))
+}
+
+fn calculate_total_winnings_with_jokers(hands_and_bids: &[(&str, u32)]) -> Result<u64, Error> {
+  let mut hands_with_strength_and_bids = hands_and_bids.iter()
+    .map(|&(hand, bid)| {
+      let (hand_resolved, hand_strength) = resolve_hand_with_jokers(hand);
+
+      (hand_resolved, hand_strength, bid)
+    })
+    .collect::<Vec<([u32; 5], u32, u32)>>();
+
+  hands_with_strength_and_bids.sort_by(|a, b| {
+    let (a_hand, a_strength, _) = a;
+    let (b_hand, b_strength, _) = b;
+
+    let strength_ord = a_strength.cmp(b_strength);
+    if strength_ord != Ordering::Equal {
+      return strength_ord;
+    }
+
+    for i in 0..5 {
+      let hand_ord = a_hand[i].cmp(&b_hand[i]);
+      if hand_ord != Ordering::Equal {
+        return hand_ord;
+      }
+    }
+
+    Ordering::Equal
+  });
+
+  hands_with_strength_and_bids
+    .iter()
+    .enumerate()
+    .try_fold(0u64, |total, (index, &(_, _, bid))| total.checked_add((index as u64 + 1) * bid as u64))
+    .ok_or_else(|| Error::unsolvable("the winnings add up to too much"))
+}
+
+/// `size` hands with bids of up to 1000. Hands are drawn with repeated cards favoured, so every
+/// hand type turns up.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  const CARDS: [char; 13] = ['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A'];
+
+  let mut contents = String::new();
+
+  for _ in 0..size {
+    let mut hand = String::new();
+
+    for _ in 0..5 {
+      let card = match hand.chars().last() {
+        Some(previous) if rng.chance(1, 3) => previous,
+        _ => *rng.choose(&CARDS),
+      };
+
+      hand.push(card);
+    }
+
+    let mut cards = hand.chars().collect::<Vec<char>>();
+    rng.shuffle(&mut cards);
+
+    contents.push_str(&format!("{} {}\n", cards.into_iter().collect::<String>(), rng.range(1..1001)));
+  }
+
+  contents
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+  type Input<'a> = Vec<(&'a str, u32)>;
+
+  type Config = ();
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    contents
+      .lines()
+      .enumerate()
+      .map(|(index, line)| parse_line(index + 1, line))
+      .collect()
+  }
+
+  fn part1(hands_and_bids: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    calculate_total_winnings(hands_and_bids).map(Answer::from)
+  }
+
+  fn part2(hands_and_bids: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    calculate_total_winnings_with_jokers(hands_and_bids).map(Answer::from)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{fuzz::assert_no_panic, generate::assert_solvable};
+
+  use super::*;
+
+  const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+  #[test]
+  fn part1_example() {
+    let input = Day07::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day07::part1(&input, &()), Ok(Answer::Unsigned(6440)));
+  }
+
+  #[test]
+  fn part2_example() {
+    let input = Day07::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day07::part2(&input, &()), Ok(Answer::Unsigned(5905)));
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day07>(generate, 0..20, 50);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day07>(generate, 0..100, 50);
+  }
+}
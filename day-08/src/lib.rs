@@ -0,0 +1,405 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{Answer, Direction, Error, Solution, cycle::{Cycle, brent, first_common_hit}, parser::{delimited, identifier, parse_line, separated_pair, tag, ws}, random::Rng};
+
+fn extract_navigation_instructions(contents: &str) -> Result<Vec<Direction>, Error> {
+  match contents.lines().next() {
+    Some("") | None => {
+      Err(Error::parse(1, 0, "missing navigation instructions"))
+    },
+    Some(line) => {
+      line
+        .chars()
+        .enumerate()
+        .map(|(index, char)| {
+          match Direction::from_char(char) {
+            Some(direction @ (Direction::Left | Direction::Right)) => Ok(direction),
+            _ => Err(Error::parse(1, index + 1, format!("invalid navigation instruction \"{}\"", char))),
+          }
+        })
+        .collect()
+    },
+  }
+}
+
+fn partition_node_line(line_number: usize, line: &str) -> Result<(&str, &str, &str), Error> {
+  let targets = delimited(ws(tag("(")), separated_pair(ws(identifier), ws(tag(",")), ws(identifier)), ws(tag(")")));
+
+  parse_line(line_number, line, separated_pair(identifier, ws(tag("=")), targets))
+    .map(|(key, (left, right))| (key, left, right))
+}
+
+#[derive(Debug)]
+struct Node<'a> {
+  key: &'a str,
+  left_index: usize,
+  right_index: usize,
+  is_start: bool,
+  is_end: bool,
+}
+
+fn make_navigation_tree<'a>(contents: &'a str) -> Result<Vec<Node<'a>>, Error> {
+  let mut index_map = HashMap::new();
+  let mut transient_list = Vec::new();
+
+  let lines = contents
+    .lines()
+    .enumerate()
+    .skip(1)
+    .filter(|&(_, line)| !line.is_empty());
+
+  for (line_index, line) in lines {
+    let (key, left, right) = partition_node_line(line_index + 1, line)?;
+
+    index_map.insert(key, transient_list.len());
+    transient_list.push((line_index + 1, line, key, left, right, key.ends_with('A'), key.ends_with('Z')));
+  }
+
+  let navigation_tree = transient_list
+    .iter()
+    .map(|&(line_number, line, key, left, right, is_start, is_end)| {
+      if let Some(&left_index) = index_map.get(left) {
+        if let Some(&right_index) = index_map.get(right) {
+          Ok(Node{key, left_index, right_index, is_start, is_end})
+        } else {
+          Err(Error::parse_at(line_number, line, right, format!("unknown right node \"{}\"", right)))
+        }
+      } else {
+        Err(Error::parse_at(line_number, line, left, format!("unknown left node \"{}\"", left)))
+      }
+    })
+    .collect::<Result<Vec<Node>, Error>>()?;
+
+  Ok(navigation_tree)
+}
+
+/// Steps from AAA to ZZZ. Every `(node, instruction)` state is reached within that many steps,
+/// so a walk that takes longer never reaches ZZZ.
+fn navigate_tree(navigation_instructions: &[Direction], aaa_index: usize, zzz_index: usize, navigation_tree: &[Node]) -> Result<usize, Error> {
+  let mut i = 0usize;
+  let mut next_node_index = aaa_index;
+
+  while i <= navigation_tree.len() * navigation_instructions.len() {
+    next_node_index = match &navigation_instructions[i % navigation_instructions.len()] {
+      Direction::Left => navigation_tree[next_node_index].left_index,
+      _ => navigation_tree[next_node_index].right_index,
+    };
+
+    i += 1;
+
+    if next_node_index == zzz_index {
+      return Ok(i);
+    }
+  }
+
+  Err(Error::unsolvable("ZZZ cannot be reached from AAA"))
+}
+
+/// Follows one ghost until its `(node, instruction)` state repeats, noting the steps at which it
+/// stands on an end node.
+fn find_end_node_hits(navigation_instructions: &[Direction], navigation_tree: &[Node], start_node_index: usize) -> (Cycle, Vec<usize>) {
+  let step = |&(node_index, instruction_index): &(usize, usize)| {
+    let next_node_index = match &navigation_instructions[instruction_index] {
+      Direction::Left => navigation_tree[node_index].left_index,
+      _ => navigation_tree[node_index].right_index,
+    };
+
+    (next_node_index, (instruction_index + 1) % navigation_instructions.len())
+  };
+
+  let start = (start_node_index, 0);
+  let cycle = brent(&start, &step);
+
+  let mut hits = vec![];
+  let mut state = start;
+  for steps in 0..(cycle.prefix + cycle.length) {
+    if navigation_tree[state.0].is_end {
+      hits.push(steps);
+    }
+
+    state = step(&state);
+  }
+
+  (cycle, hits)
+}
+
+fn navigate_tree_as_ghosts(navigation_instructions: &[Direction], navigation_tree: &[Node]) -> Result<usize, Error> {
+  let ghosts = navigation_tree
+    .iter()
+    .enumerate()
+    .filter(|(_, node)| node.is_start)
+    .map(|(start_node_index, _)| find_end_node_hits(navigation_instructions, navigation_tree, start_node_index))
+    .collect::<Vec<(Cycle, Vec<usize>)>>();
+
+  if ghosts.is_empty() {
+    return Err(Error::unsolvable("no starting nodes found"));
+  }
+
+  match first_common_hit(&ghosts) {
+    Some(steps) => Ok(steps),
+    None => Err(Error::unsolvable("ghosts never stand on end nodes at the same time")),
+  }
+}
+
+pub struct Network<'a> {
+  navigation_instructions: Vec<Direction>,
+  navigation_tree: Vec<Node<'a>>,
+}
+
+/// A network shaped like the puzzle's: one to four ghosts, each following its own loop of up to
+/// about `size` nodes that ends on a `Z` node after a whole number of passes through the
+/// instructions. The first ghost walks from `AAA` to `ZZZ`. Turns off a ghost's path lead to
+/// other nodes on it.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  let instructions = (0..rng.range(1..(size as i64 / 4).max(1) + 1)).map(|_| *rng.choose(&['L', 'R'])).collect::<Vec<char>>();
+
+  let mut names = HashSet::from([String::from("AAA"), String::from("ZZZ")]);
+  let mut name = |rng: &mut Rng, last: Option<char>| loop {
+    let mut name = (0..2).map(|_| char::from(b'A' + rng.below(26) as u8)).collect::<String>();
+    name.push(last.unwrap_or_else(|| char::from(b'B' + rng.below(24) as u8)));
+
+    if names.insert(name.clone()) {
+      return name;
+    }
+  };
+
+  let mut lines = vec![];
+
+  for ghost in 0..rng.range(1..5) {
+    let length = instructions.len() * rng.range(1..(size / instructions.len()).max(1) as i64 + 1) as usize;
+
+    let mut path = vec![];
+    path.push(if ghost == 0 { String::from("AAA") } else { name(rng, Some('A')) });
+    for _ in 1..length {
+      path.push(name(rng, None));
+    }
+    path.push(if ghost == 0 { String::from("ZZZ") } else { name(rng, Some('Z')) });
+
+    for (step, node) in path.iter().enumerate() {
+      let next = &path[if step == length { 1 } else { step + 1 }];
+      let other = rng.choose(&path[1..]);
+
+      let (left, right) = match instructions[step % instructions.len()] {
+        'L' => (next, other),
+        _ => (other, next),
+      };
+
+      lines.push(format!("{node} = ({left}, {right})"));
+    }
+  }
+
+  rng.shuffle(&mut lines);
+
+  format!("{}\n\n{}\n", instructions.into_iter().collect::<String>(), lines.join("\n"))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+  type Input<'a> = Network<'a>;
+
+  type Config = ();
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    let navigation_instructions = extract_navigation_instructions(contents)?;
+
+    let navigation_tree = make_navigation_tree(contents)?;
+
+    Ok(Network{ navigation_instructions, navigation_tree })
+  }
+
+  fn part1(network: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    let navigation_tree = &network.navigation_tree;
+
+    let aaa_index = match navigation_tree.iter().enumerate().find(|(_, node)| node.key == "AAA") {
+      Some((aaa_index, _)) => aaa_index,
+      None => return Err(Error::unsolvable("no AAA node")),
+    };
+    let zzz_index = match navigation_tree.iter().enumerate().find(|(_, node)| node.key == "ZZZ") {
+      Some((zzz_index, _)) => zzz_index,
+      None => return Err(Error::unsolvable("no ZZZ node")),
+    };
+
+    navigate_tree(&network.navigation_instructions, aaa_index, zzz_index, navigation_tree).map(Answer::from)
+  }
+
+  fn part2(network: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    navigate_tree_as_ghosts(&network.navigation_instructions, &network.navigation_tree)
+      .map(|steps| steps.into())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{differential::assert_agree, fuzz::assert_no_panic, generate::assert_solvable, solution::solve};
+
+  use super::*;
+
+  /// Beyond this many steps both solvers count as finding no answer.
+  const STEP_LIMIT: usize = 10_000;
+
+  /// Walks every ghost at once until they all stand on end nodes.
+  fn walk_ghosts_together(network: &Network) -> Option<usize> {
+    let instructions = &network.navigation_instructions;
+    let tree = &network.navigation_tree;
+
+    let mut positions = (0..tree.len()).filter(|&index| tree[index].is_start).collect::<Vec<usize>>();
+    if positions.is_empty() {
+      return None;
+    }
+
+    for steps in 0..STEP_LIMIT {
+      if positions.iter().all(|&index| tree[index].is_end) {
+        return Some(steps);
+      }
+
+      for position in positions.iter_mut() {
+        *position = match instructions[steps % instructions.len()] {
+          Direction::Left => tree[*position].left_index,
+          _ => tree[*position].right_index,
+        };
+      }
+    }
+
+    None
+  }
+
+  /// A few short instructions and a handful of nodes named `00A`, `01Z`, `02X` and so on.
+  fn generate_network(rng: &mut Rng) -> String {
+    let instructions = (0..rng.range(1..5)).map(|_| *rng.choose(&['L', 'R'])).collect::<String>();
+
+    let count = rng.range(2..10) as usize;
+    let names = (0..count)
+      .map(|index| format!("{index:02}{}", if index == 0 { 'A' } else { *rng.choose(&['A', 'Z', 'X', 'X']) }))
+      .collect::<Vec<String>>();
+
+    let mut contents = format!("{instructions}\n\n");
+    for name in names.iter() {
+      contents.push_str(&format!("{name} = ({}, {})\n", rng.choose(&names), rng.choose(&names)));
+    }
+
+    contents
+  }
+
+  #[test]
+  fn ghost_cycles_agree_with_walking_together() {
+    let fast = |contents: &str| {
+      let network = Day08::parse(contents).unwrap();
+
+      navigate_tree_as_ghosts(&network.navigation_instructions, &network.navigation_tree).ok().filter(|&steps| steps < STEP_LIMIT)
+    };
+
+    assert_agree(0..1000, generate_network, fast, |contents| walk_ghosts_together(&Day08::parse(contents).unwrap()));
+  }
+
+  const EXAMPLE_1: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+
+  const EXAMPLE_2: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+
+  const EXAMPLE_3: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+
+  /// End nodes are first reached after 2 and 1 steps, then every 3 and every 2 steps.
+  const OFFSET_GHOSTS: &str = "\
+L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11C, 11C)
+11C = (11D, 11D)
+11D = (11Z, 11Z)
+22A = (22Z, 22Z)
+22Z = (22B, 22B)
+22B = (22Z, 22Z)
+";
+
+  #[test]
+  fn part1_example() {
+    let input = Day08::parse(EXAMPLE_1).unwrap();
+
+    assert_eq!(Day08::part1(&input, &()), Ok(Answer::Unsigned(2)));
+  }
+
+  #[test]
+  fn part1_example_with_repeated_instructions() {
+    let input = Day08::parse(EXAMPLE_2).unwrap();
+
+    assert_eq!(Day08::part1(&input, &()), Ok(Answer::Unsigned(6)));
+  }
+
+  #[test]
+  fn part2_example() {
+    let input = Day08::parse(EXAMPLE_3).unwrap();
+
+    assert_eq!(Day08::part2(&input, &()), Ok(Answer::Unsigned(6)));
+  }
+
+  #[test]
+  fn part2_ghost_cycles_not_starting_at_zero() {
+    let input = Day08::parse(OFFSET_GHOSTS).unwrap();
+
+    assert_eq!(Day08::part2(&input, &()), Ok(Answer::Unsigned(5)));
+  }
+
+  #[test]
+  fn missing_start_node_is_unsolvable() {
+    let input = Day08::parse(EXAMPLE_3).unwrap();
+
+    assert!(matches!(Day08::part1(&input, &()), Err(Error::Unsolvable(_))));
+  }
+
+  #[test]
+  fn unreachable_end_node_is_unsolvable() {
+    let input = Day08::parse("L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+
+    assert!(matches!(Day08::part1(&input, &()), Err(Error::Unsolvable(_))));
+  }
+
+  #[test]
+  fn short_node_lines_are_parse_errors() {
+    assert_eq!(solve::<Day08>("L\n\nAAA = (ZZZ", &[1]), vec![Err(Error::parse(3, 11, "expected \",\""))]);
+  }
+
+  #[test]
+  fn crlf_input_is_normalized() {
+    let contents = format!("\u{feff}{}", EXAMPLE_2.replace('\n', "\r\n"));
+
+    assert_eq!(solve::<Day08>(&contents, &[1]), vec![Ok(Answer::Unsigned(6))]);
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day08>(generate, 0..20, 30);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day08>(generate, 0..100, 30);
+  }
+}
@@ -0,0 +1,177 @@
+use aoc_common::{Answer, Error, Solution, extract::separated_integers, random::Rng};
+
+fn parse_line(line: &str) -> Result<Vec<i64>, Error> {
+  separated_integers::<i64>(line).map_err(|failure| failure.into_error(0, line))
+}
+
+fn out_of_range() -> Error {
+  Error::unsolvable("history values are too large to extrapolate")
+}
+
+fn extrapolate_last_value(values: &mut Vec<i64>) -> Result<i64, Error> {
+  for start_index in 1..values.len() {
+    for i in (start_index..values.len()).rev() {
+      values[i] = match values[i].checked_sub(values[i - 1]) {
+        Some(difference) => difference,
+        None => return Err(out_of_range()),
+      };
+    }
+
+    let has_only_zeroes = &values[start_index..].iter().all(|&value| value == 0);
+    if *has_only_zeroes {
+      break;
+    }
+  }
+
+  values.push(0);
+
+  for start_index in 1..values.len() {
+    for i in (start_index..values.len()).rev() {
+      values[i] = match values[i].checked_add(values[i - 1]) {
+        Some(sum) => sum,
+        None => return Err(out_of_range()),
+      };
+    }
+  }
+
+  match values.last() {
+    Some(&last_value) => Ok(last_value),
+    None => Err(Error::unsolvable("empty history")),
+  }
+}
+
+fn extrapolate_first_value(values: &mut [i64]) -> Result<i64, Error> {
+  for start_index in 1..values.len() {
+    for i in (start_index..values.len()).rev() {
+      values[i] = match values[i].checked_sub(values[i - 1]) {
+        Some(difference) => difference,
+        None => return Err(out_of_range()),
+      };
+    }
+
+    let has_only_zeroes = &values[start_index..].iter().all(|&value| value == 0);
+    if *has_only_zeroes {
+      break;
+    }
+  }
+
+  let mut last_value = 0;
+  for i in (0..values.len()).rev() {
+    last_value = match values[i].checked_sub(last_value) {
+      Some(value) => value,
+      None => return Err(out_of_range()),
+    };
+  }
+
+  Ok(last_value)
+}
+
+/// `size` histories of 21 values, each following a polynomial of degree at most five.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  let mut contents = String::new();
+
+  for _ in 0..size {
+    let degree = rng.range(0..6) as usize;
+    let mut differences = (0..=degree).map(|_| rng.range(-20..21)).collect::<Vec<i64>>();
+
+    let mut values = vec![];
+    for _ in 0..21 {
+      values.push(differences[0].to_string());
+
+      for index in 0..degree {
+        differences[index] += differences[index + 1];
+      }
+    }
+
+    contents.push_str(&values.join(" "));
+    contents.push('\n');
+  }
+
+  contents
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+  type Input<'a> = Vec<Vec<i64>>;
+
+  type Config = ();
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    contents
+      .lines()
+      .enumerate()
+      .map(|(index, line)| parse_line(line).map_err(|error| error.on_line(index + 1)))
+      .collect()
+  }
+
+  fn part1(histories: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    histories
+      .iter()
+      .try_fold(0i64, |sum, values| {
+        match extrapolate_last_value(&mut values.clone()) {
+          Ok(value) => sum.checked_add(value).ok_or_else(out_of_range),
+          Err(error) => Err(error),
+        }
+      })
+      .map(|sum| sum.into())
+  }
+
+  fn part2(histories: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    histories
+      .iter()
+      .try_fold(0i64, |sum, values| {
+        match extrapolate_first_value(&mut values.clone()) {
+          Ok(value) => sum.checked_add(value).ok_or_else(out_of_range),
+          Err(error) => Err(error),
+        }
+      })
+      .map(|sum| sum.into())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{fuzz::assert_no_panic, generate::assert_solvable};
+
+  use super::*;
+
+  const EXAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+  #[test]
+  fn part1_example() {
+    let input = Day09::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day09::part1(&input, &()), Ok(Answer::Signed(114)));
+  }
+
+  #[test]
+  fn part2_example() {
+    let input = Day09::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day09::part2(&input, &()), Ok(Answer::Signed(2)));
+  }
+
+  #[test]
+  fn stray_characters_between_numbers_are_refused() {
+    let result = Day09::parse("0 3 6 9 12 15\n0 3 6 9 1x2 15");
+    assert_eq!(result.err(), Some(Error::parse(2, 10, "expected whitespace or \",\" after a number").spanning(2)));
+
+    let result = Day09::parse("a b");
+    assert_eq!(result.err(), Some(Error::parse(1, 1, "expected a number").spanning(1)));
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day09>(generate, 0..20, 20);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day09>(generate, 0..100, 20);
+  }
+}
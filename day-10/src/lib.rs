@@ -0,0 +1,314 @@
+use aoc_common::{Answer, Direction, Error, Grid, Solution, generate::rectilinear_loop, geometry::{Point, interior_points}, random::Rng};
+
+pub struct PipeMap {
+  start_position: (usize, usize),
+  schema: Grid<char>,
+}
+
+fn parse_pipe_map(contents: &str) -> Result<PipeMap, Error> {
+  let schema = Grid::parse(contents)?;
+
+  let start_position = match schema.find(|&symbol| symbol == 'S') {
+    Some(start_position) => start_position,
+    None => return Err(Error::unsolvable("no starting position")),
+  };
+
+  Ok(PipeMap{
+    start_position,
+    schema,
+  })
+}
+
+fn pipe_connections(symbol: char) -> Option<[Direction; 2]> {
+  match symbol {
+    '|' => Some([Direction::Up, Direction::Down]),
+    '-' => Some([Direction::Left, Direction::Right]),
+    'L' => Some([Direction::Up, Direction::Right]),
+    'J' => Some([Direction::Up, Direction::Left]),
+    '7' => Some([Direction::Down, Direction::Left]),
+    'F' => Some([Direction::Down, Direction::Right]),
+    _ => None,
+  }
+}
+
+/// Direction leaving a pipe that was entered while moving in `direction`.
+fn next_direction(symbol: char, direction: Direction) -> Option<Direction> {
+  let [a, b] = pipe_connections(symbol)?;
+
+  if a == direction.reverse() {
+    Some(b)
+  } else if b == direction.reverse() {
+    Some(a)
+  } else {
+    None
+  }
+}
+
+fn step(pipe_map: &PipeMap, position: (usize, usize), direction: Direction) -> Result<(usize, usize), Error> {
+  match pipe_map.schema.offset(position, direction.delta()) {
+    Some(position) => Ok(position),
+    None => Err(Error::unsolvable("path leaves the map")),
+  }
+}
+
+fn find_start_direction(pipe_map: &PipeMap) -> Result<Direction, Error> {
+  for direction in [Direction::Up, Direction::Down, Direction::Left, Direction::Right] {
+    let connects_back = pipe_map.schema
+      .offset(pipe_map.start_position, direction.delta())
+      .and_then(|position| pipe_connections(pipe_map.schema[position]))
+      .is_some_and(|connections| connections.contains(&direction.reverse()));
+
+    if connects_back {
+      return Ok(direction);
+    }
+  }
+
+  Err(Error::unsolvable("no loop found from starting position"))
+}
+
+/// Walks the loop once, calling `visit` with every position and the directions of travel into and
+/// out of it. The starting position is visited last.
+fn walk_loop<F>(pipe_map: &PipeMap, start_direction: Direction, mut visit: F) -> Result<(), Error>
+where
+  F: FnMut((usize, usize), Direction, Direction),
+{
+  let mut position = pipe_map.start_position;
+  let mut direction = start_direction;
+
+  loop {
+    position = step(pipe_map, position, direction)?;
+
+    let symbol = pipe_map.schema[position];
+    if symbol == 'S' {
+      visit(position, direction, start_direction);
+      return Ok(());
+    }
+
+    let next = match next_direction(symbol, direction) {
+      Some(next) => next,
+      None => return Err(Error::unsolvable("unexpected end of the path")),
+    };
+
+    visit(position, direction, next);
+    direction = next;
+  }
+}
+
+fn find_loop_length(pipe_map: &PipeMap, start_direction: Direction) -> Result<usize, Error> {
+  let mut length = 0;
+
+  walk_loop(pipe_map, start_direction, |_, _, _| length += 1)
+    .map(|_| length)
+}
+
+/// Corners of the loop, which is a simple polygon since every tile holds at most one pipe.
+fn find_loop_corners(pipe_map: &PipeMap, start_direction: Direction) -> Result<Vec<Point>, Error> {
+  let mut corners = vec![];
+
+  let walked = walk_loop(pipe_map, start_direction, |(row, col), direction, next| {
+    if direction != next {
+      corners.push((col as i64, row as i64));
+    }
+  });
+
+  walked.map(|_| corners)
+}
+
+/// A loop winding through a grid of about `size` by `size` tiles, with stray pipes everywhere
+/// except next to the start. The corners are spread two tiles apart so that even the narrowest
+/// stretch of the loop encloses a row of tiles.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  let nodes = (size / 4).max(2);
+  let corners = rectilinear_loop(rng, nodes, nodes)
+    .into_iter()
+    .map(|(x, y)| (x * 2, y * 2))
+    .collect::<Vec<Point>>();
+
+  let mut points = vec![];
+  for (index, &(x, y)) in corners.iter().enumerate() {
+    let (nx, ny) = corners[(index + 1) % corners.len()];
+    let (dx, dy) = ((nx - x).signum(), (ny - y).signum());
+
+    for step in 0..(nx - x).abs().max((ny - y).abs()) {
+      points.push((x + dx * step + 1, y + dy * step + 1));
+    }
+  }
+
+  let mut schema = Grid::new(nodes * 4 + 1, nodes * 4 + 1, '.');
+  for (index, &(x, y)) in points.iter().enumerate() {
+    let previous = points[(index + points.len() - 1) % points.len()];
+    let next = points[(index + 1) % points.len()];
+
+    let symbol = match [(previous.0 - x, previous.1 - y), (next.0 - x, next.1 - y)] {
+      [(0, _), (0, _)] => '|',
+      [(_, 0), (_, 0)] => '-',
+      pair if pair.contains(&(0, -1)) && pair.contains(&(1, 0)) => 'L',
+      pair if pair.contains(&(0, -1)) => 'J',
+      pair if pair.contains(&(-1, 0)) => '7',
+      _ => 'F',
+    };
+
+    schema[(y as usize, x as usize)] = symbol;
+  }
+
+  let (start_x, start_y) = *rng.choose(&points);
+  let start = (start_y as usize, start_x as usize);
+  schema[start] = 'S';
+
+  let near_start = |(row, col): (usize, usize)| row.abs_diff(start.0) + col.abs_diff(start.1) <= 1;
+
+  for position in schema.positions().collect::<Vec<(usize, usize)>>() {
+    if schema[position] == '.' && !near_start(position) && rng.chance(1, 2) {
+      schema[position] = *rng.choose(&['|', '-', 'L', 'J', '7', 'F']);
+    }
+  }
+
+  format!("{schema}\n")
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+  type Input<'a> = PipeMap;
+
+  type Config = ();
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    parse_pipe_map(contents)
+  }
+
+  fn part1(pipe_map: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    let start_direction = find_start_direction(pipe_map)?;
+
+    find_loop_length(pipe_map, start_direction)
+      .map(|length| (length / 2).into())
+  }
+
+  fn part2(pipe_map: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    let start_direction = find_start_direction(pipe_map)?;
+
+    find_loop_corners(pipe_map, start_direction)
+      .map(|corners| interior_points(&corners).into())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{fuzz::assert_no_panic, generate::assert_solvable};
+
+  use super::*;
+
+  const EXAMPLE_1: &str = "\
+.....
+.S-7.
+.|.|.
+.L-J.
+.....
+";
+
+  const EXAMPLE_2: &str = "\
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+";
+
+  const EXAMPLE_3: &str = "\
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+";
+
+  const EXAMPLE_4: &str = "\
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+";
+
+  const EXAMPLE_5: &str = "\
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+";
+
+  #[test]
+  fn part1_example_simple_loop() {
+    let input = Day10::parse(EXAMPLE_1).unwrap();
+
+    assert_eq!(Day10::part1(&input, &()), Ok(Answer::Unsigned(4)));
+  }
+
+  #[test]
+  fn part1_example_complex_loop() {
+    let input = Day10::parse(EXAMPLE_2).unwrap();
+
+    assert_eq!(Day10::part1(&input, &()), Ok(Answer::Unsigned(8)));
+  }
+
+  #[test]
+  fn part2_example_enclosed_tiles() {
+    let input = Day10::parse(EXAMPLE_3).unwrap();
+
+    assert_eq!(Day10::part2(&input, &()), Ok(Answer::Unsigned(4)));
+  }
+
+  #[test]
+  fn part2_example_larger() {
+    let input = Day10::parse(EXAMPLE_4).unwrap();
+
+    assert_eq!(Day10::part2(&input, &()), Ok(Answer::Unsigned(8)));
+  }
+
+  #[test]
+  fn part2_example_with_junk() {
+    let input = Day10::parse(EXAMPLE_5).unwrap();
+
+    assert_eq!(Day10::part2(&input, &()), Ok(Answer::Unsigned(10)));
+  }
+  #[test]
+  fn start_on_bottom_row() {
+    let input = Day10::parse("F-7\n|.|\nLSJ").unwrap();
+
+    assert_eq!(Day10::part1(&input, &()), Ok(Answer::Unsigned(4)));
+  }
+
+  #[test]
+  fn generated_loops_enclose_tiles() {
+    for seed in 0..20 {
+      let pipe_map = Day10::parse(&generate(&mut Rng::new(seed), 12)).unwrap();
+
+      assert_ne!(Day10::part2(&pipe_map, &()), Ok(Answer::Unsigned(0)), "seed {seed}");
+    }
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day10>(generate, 0..20, 12);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day10>(generate, 0..100, 12);
+  }
+}
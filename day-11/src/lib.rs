@@ -0,0 +1,201 @@
+use std::num::NonZeroUsize;
+
+use aoc_common::{Answer, Error, Grid, Solution, config::{self, parse_positive}, generate::grid, random::Rng};
+
+#[allow(clippy::type_complexity)]
+fn parse_galaxies(contents: &str) -> Result<(Vec<(usize, usize)>, usize, usize), Error> {
+  let image = Grid::parse_with(contents, |symbol| {
+    match symbol {
+      '#' => Some(true),
+      '.' => Some(false),
+      _ => None,
+    }
+  });
+
+  let image = image?;
+
+  let galaxies = image.positions().filter(|&position| image[position]).collect();
+
+  Ok((galaxies, image.rows(), image.cols()))
+}
+
+/// The galaxies with every empty row and column grown to `expansion_rate`, or `None` if a
+/// coordinate no longer fits in a `usize`.
+fn expand_space(galaxies: Vec<(usize, usize)>, expansion_rate: NonZeroUsize, rows: usize, cols: usize) -> Option<Vec<(usize, usize)>> {
+  let mut spaces_vertical = vec![true; rows];
+  let mut spaces_horizontal = vec![true; cols];
+
+  for &(row, col) in galaxies.iter() {
+    spaces_vertical[row] = false;
+    spaces_horizontal[col] = false;
+  }
+
+  let mut summed_area_spaces_vertical = vec![0; rows];
+  let mut summed_area_spaces_horizontal = vec![0; cols];
+
+  let mut current_spaces_vertical_sum = 0usize;
+  for i in 0..summed_area_spaces_vertical.len() {
+    if spaces_vertical[i] {
+      current_spaces_vertical_sum = current_spaces_vertical_sum.checked_add(expansion_rate.get() - 1)?;
+    }
+
+    summed_area_spaces_vertical[i] = current_spaces_vertical_sum;
+  }
+
+  let mut current_spaces_horizontal_sum = 0usize;
+  for i in 0..summed_area_spaces_horizontal.len() {
+    if spaces_horizontal[i] {
+      current_spaces_horizontal_sum = current_spaces_horizontal_sum.checked_add(expansion_rate.get() - 1)?;
+    }
+
+    summed_area_spaces_horizontal[i] = current_spaces_horizontal_sum;
+  }
+
+  let mut galaxies = galaxies;
+  for i in 0..galaxies.len() {
+    galaxies[i] = (galaxies[i].0.checked_add(summed_area_spaces_vertical[galaxies[i].0])?, galaxies[i].1.checked_add(summed_area_spaces_horizontal[galaxies[i].1])?);
+  }
+
+  Some(galaxies)
+}
+
+fn manhatan_distance(a: &(usize, usize), b: &(usize, usize)) -> usize {
+  (a.0.max(b.0) - a.0.min(b.0)) + (a.1.max(b.1) - a.1.min(b.1))
+}
+
+/// The distances between every pair of galaxies added up, or `None` if the sum overflows.
+fn calculate_sum_of_shortest_paths(galaxies: Vec<(usize, usize)>) -> Option<usize> {
+  let mut distance = 0usize;
+
+  for i in 0..galaxies.len() {
+    for j in (i + 1)..galaxies.len() {
+      distance = distance.checked_add(manhatan_distance(&galaxies[i], &galaxies[j]))?;
+    }
+  }
+
+  Some(distance)
+}
+
+fn solve(image: &Image, expansion_rate: NonZeroUsize) -> Result<Answer, Error> {
+  let sum = expand_space(image.galaxies.clone(), expansion_rate, image.rows, image.cols).and_then(calculate_sum_of_shortest_paths);
+
+  match sum {
+    Some(sum) => Ok(sum.into()),
+    None => Err(Error::unsolvable("expanded distances are too large to add up")),
+  }
+}
+
+pub struct Image {
+  galaxies: Vec<(usize, usize)>,
+  rows: usize,
+  cols: usize,
+}
+
+/// A `size` by `size` image with about one galaxy in every dozen tiles.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  grid(rng, size, size, |rng| if rng.chance(1, 12) { '#' } else { '.' })
+}
+
+/// Part 1 doubles every empty row and column.
+const DOUBLED: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+
+const MILLIONFOLD: NonZeroUsize = NonZeroUsize::new(1000000).unwrap();
+
+pub struct Config {
+  /// How many rows or columns each empty one becomes in part 2; part 1 always doubles them.
+  pub expansion_rate: NonZeroUsize,
+}
+
+impl Default for Config {
+  fn default() -> Self {
+    Config{ expansion_rate: MILLIONFOLD }
+  }
+}
+
+impl config::Config for Config {
+  const FLAGS: &'static [(&'static str, &'static str)] = &[("expansion-rate", "n")];
+
+  fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+    parse_positive(name, value).map(|expansion_rate| self.expansion_rate = expansion_rate)
+  }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+  type Input<'a> = Image;
+
+  type Config = Config;
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    parse_galaxies(contents).map(|(galaxies, rows, cols)| Image{ galaxies, rows, cols })
+  }
+
+  fn part1(image: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    solve(image, DOUBLED)
+  }
+
+  fn part2(image: &Self::Input<'_>, config: &Self::Config) -> Result<Answer, Error> {
+    solve(image, config.expansion_rate)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{fuzz::assert_no_panic, generate::assert_solvable};
+
+  use super::*;
+
+  const EXAMPLE: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+";
+
+  #[test]
+  fn part1_example() {
+    let input = Day11::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day11::part1(&input, &Config::default()), Ok(Answer::Unsigned(374)));
+  }
+
+  #[test]
+  fn expansion_rate_10_example() {
+    let input = Day11::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day11::part2(&input, &Config{ expansion_rate: NonZeroUsize::new(10).unwrap() }), Ok(Answer::Unsigned(1030)));
+  }
+
+  #[test]
+  fn expansion_rate_100_example() {
+    let input = Day11::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day11::part2(&input, &Config{ expansion_rate: NonZeroUsize::new(100).unwrap() }), Ok(Answer::Unsigned(8410)));
+  }
+
+  #[test]
+  fn huge_expansion_rates_are_unsolvable() {
+    let input = Day11::parse(EXAMPLE).unwrap();
+    let too_large = Err(Error::unsolvable("expanded distances are too large to add up"));
+
+    assert_eq!(Day11::part2(&input, &Config{ expansion_rate: NonZeroUsize::new(usize::MAX).unwrap() }), too_large);
+    assert_eq!(Day11::part2(&input, &Config{ expansion_rate: NonZeroUsize::new(usize::MAX / 8).unwrap() }), too_large);
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day11>(generate, 0..20, 12);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day11>(generate, 0..100, 12);
+  }
+}
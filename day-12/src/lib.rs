@@ -0,0 +1,293 @@
+use std::collections::HashMap;
+
+use aoc_common::{Answer, Error, Solution, config::{self, parse_positive}, random::Rng};
+
+// operational (.)
+// damaged (#)
+// unknown (?)
+
+fn parse_record(line_number: usize, line: &str) -> Result<(&str, Vec<usize>), Error> {
+  let space_pos = match line.find(' ') {
+    Some(space_pos) => space_pos,
+    None => return Err(Error::parse(line_number, 0, "missing \" \" between conditions and groups")),
+  };
+
+  let (conditions, groups) = line.split_at(space_pos);
+
+  if let Some((index, symbol)) = conditions.char_indices().find(|&(_, symbol)| !matches!(symbol, '.' | '#' | '?')) {
+    return Err(Error::parse(line_number, index + 1, format!("invalid condition \"{}\"", symbol)).spanning(symbol.len_utf8()));
+  }
+
+  let groups = groups
+    .trim()
+    .split(',')
+    .map(|grouping| {
+      match grouping.parse::<usize>() {
+        Ok(group) if group > 0 && group <= conditions.len() => Ok(group),
+        Ok(group) => Err(Error::parse_at(line_number, line, grouping, format!("group of {} does not fit in {} springs", group, conditions.len()))),
+        Err(error) => Err(Error::parse_at(line_number, line, grouping, format!("invalid group \"{}\": {}", grouping, error))),
+      }
+    })
+    .collect();
+
+  match groups {
+    Ok(groups) => Ok((conditions, groups)),
+    Err(error) => Err(error)
+  }
+}
+
+fn parse_records(contents: &str) -> Result<Vec<(&str, Vec<usize>)>, Error> {
+  contents
+    .lines()
+    .enumerate()
+    .map(|(index, line)| parse_record(index + 1, line))
+    .collect()
+}
+
+fn conditions_has_part(conditions: &str, part: char) -> bool {
+  conditions.chars().any(|symbol| symbol == part)
+}
+
+fn possible_arrangements_for_conditions(conditions: &str, group: usize) -> usize {
+  if conditions.len() < group {
+    return 0;
+  }
+
+  let mut arragements = 0;
+
+  for offset in 0..=(conditions.len() - group) {
+    let (left, conditions) = conditions.split_at(offset);
+    let (middle, right) = conditions.split_at(group);
+
+    if !conditions_has_part(left, '#') && !conditions_has_part(middle, '.') && !conditions_has_part(right, '#') {
+      arragements += 1;
+    }
+  }
+
+  arragements
+}
+
+fn min_groups_occupancy(groups: &[usize]) -> usize {
+  if groups.is_empty() {
+    return 0;
+  }
+
+  groups.iter().sum::<usize>() + groups.len() - 1
+}
+
+/// Counts the arrangements of `groups` in `conditions`, or `None` if there are too many to count.
+fn find_possible_arragements(conditions: &str, groups: &[usize], cache: &mut HashMap<(usize, usize, usize), usize>) -> Option<usize> {
+  if groups.is_empty() {
+    return Some(0);
+  }
+
+  if groups.len() == 1 {
+    return Some(possible_arrangements_for_conditions(conditions, groups[0]));
+  }
+
+  let group = groups[0];
+
+  let mut offset = 0;
+  let min_rest_groups_occupancy = min_groups_occupancy(&groups[1..]);
+  let max_offset = match conditions.len().checked_sub(group + 1 + min_rest_groups_occupancy) {
+    Some(max_offset) => max_offset,
+    None => return Some(0),
+  };
+
+  let mut arragements = 0usize;
+  while offset <= max_offset {
+    let position = match conditions.chars().skip(offset).position(|char| char == '#' || char == '?') {
+      Some(position) => position,
+      None => break,
+    };
+
+    offset += position;
+
+    if offset > max_offset {
+      break;
+    }
+
+    let (left_conditions, rest_conditions) = conditions.split_at(offset);
+    if conditions_has_part(left_conditions, '#') {
+      break;
+    }
+
+    offset += 1;
+
+    let (middle_conditions, rest_conditions) = rest_conditions.split_at(group);
+    if conditions_has_part(middle_conditions, '.') {
+      continue;
+    }
+
+    if rest_conditions.is_empty() {
+      arragements = arragements.checked_add(1)?;
+      break;
+    }
+
+    let (separator_conditions, rest_conditions) = rest_conditions.split_at(1);
+    if conditions_has_part(separator_conditions, '#') {
+      continue;
+    }
+
+    if groups.len() > 1 && rest_conditions.len() >= min_rest_groups_occupancy {
+      let key = (rest_conditions.as_ptr() as *const _ as usize, &groups[1..].as_ptr() as *const _ as usize, groups.len() - 1);
+
+      if let Some(&cached_possible_arragements) = cache.get(&key) {
+        arragements = arragements.checked_add(cached_possible_arragements)?;
+      } else {
+        let possible_arragements = find_possible_arragements(rest_conditions, &groups[1..], cache)?;
+
+        cache.insert(key, possible_arragements);
+
+        arragements = arragements.checked_add(possible_arragements)?;
+      }
+    } else {
+      break;
+    }
+  }
+
+  Some(arragements)
+}
+
+/// `copies` copies of the conditions joined by `?`, and as many copies of the groups.
+fn unfold_record(conditions: &str, groups: &[usize], copies: usize) -> (String, Vec<usize>) {
+  let unfolded_conditions = vec![conditions; copies].join("?");
+  let unfolded_groups = groups.repeat(copies);
+
+  (unfolded_conditions, unfolded_groups)
+}
+
+/// `size` records of up to twenty springs, each with at least one arrangement: the groups are laid
+/// out first and then about half the springs are hidden behind `?`.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  let mut contents = String::new();
+
+  for _ in 0..size {
+    let groups = (0..rng.range(1..5)).map(|_| rng.range(1..5) as usize).collect::<Vec<usize>>();
+
+    let mut conditions = ".".repeat(rng.below(3));
+    for (index, &group) in groups.iter().enumerate() {
+      if index > 0 {
+        conditions.push_str(&".".repeat(rng.range(1..3) as usize));
+      }
+      conditions.push_str(&"#".repeat(group));
+    }
+    conditions.push_str(&".".repeat(rng.below(3)));
+
+    let conditions = conditions.chars().map(|char| if rng.chance(1, 2) { '?' } else { char }).collect::<String>();
+    let groups = groups.iter().map(|group| group.to_string()).collect::<Vec<String>>();
+
+    contents.push_str(&format!("{conditions} {}\n", groups.join(",")));
+  }
+
+  contents
+}
+
+pub struct Config {
+  /// How many copies of each record part 2 unfolds to.
+  pub unfold: usize,
+}
+
+impl Default for Config {
+  fn default() -> Self {
+    Config{ unfold: 5 }
+  }
+}
+
+impl config::Config for Config {
+  const FLAGS: &'static [(&'static str, &'static str)] = &[("unfold", "n")];
+
+  fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+    parse_positive(name, value).map(|unfold| self.unfold = unfold.get())
+  }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+  type Input<'a> = Vec<(&'a str, Vec<usize>)>;
+
+  type Config = Config;
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    parse_records(contents)
+  }
+
+  fn part1(records: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    let sum = records
+      .iter()
+      .try_fold(0usize, |sum, (conditions, groups)| {
+        let mut cache = HashMap::new();
+
+        sum.checked_add(find_possible_arragements(conditions, &groups[..], &mut cache)?)
+      });
+
+    match sum {
+      Some(sum) => Ok(sum.into()),
+      None => Err(Error::unsolvable("too many arrangements to count")),
+    }
+  }
+
+  fn part2(records: &Self::Input<'_>, config: &Self::Config) -> Result<Answer, Error> {
+    let sum = records
+      .iter()
+      .map(|(conditions, groups)| unfold_record(conditions, groups, config.unfold))
+      .try_fold(0usize, |sum, (conditions, groups)| {
+        let mut cache = HashMap::new();
+
+        sum.checked_add(find_possible_arragements(&conditions[..], &groups[..], &mut cache)?)
+      });
+
+    match sum {
+      Some(sum) => Ok(sum.into()),
+      None => Err(Error::unsolvable("too many arrangements to count")),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{fuzz::assert_no_panic, generate::assert_solvable};
+
+  use super::*;
+
+  const EXAMPLE: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
+
+  #[test]
+  fn part1_example() {
+    let input = Day12::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day12::part1(&input, &Config::default()), Ok(Answer::Unsigned(21)));
+  }
+
+  #[test]
+  fn part2_example() {
+    let input = Day12::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day12::part2(&input, &Config::default()), Ok(Answer::Unsigned(525152)));
+  }
+
+  #[test]
+  fn unfolding_once_leaves_records_as_they_are() {
+    let input = Day12::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day12::part2(&input, &Config{ unfold: 1 }), Ok(Answer::Unsigned(21)));
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day12>(generate, 0..20, 20);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day12>(generate, 0..100, 20);
+  }
+}
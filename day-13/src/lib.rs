@@ -0,0 +1,319 @@
+use std::collections::HashSet;
+
+use aoc_common::{Answer, Error, Grid, Solution, random::Rng};
+
+fn row_masks(grid: &Grid<char>) -> Vec<usize> {
+  grid
+    .iter_rows()
+    .map(|row| {
+      row
+        .iter()
+        .enumerate()
+        .filter(|&(_, &cell)| cell == '#')
+        .fold(0, |mask, (j, _)| mask | (1 << j))
+    })
+    .collect()
+}
+
+/// Bit masks of a pattern's rows and of its columns.
+type Masks = (Vec<usize>, Vec<usize>);
+
+fn transform_pattern_to_masks(grid: &Grid<char>) -> Masks {
+  (row_masks(grid), row_masks(&grid.transpose()))
+}
+
+fn parse_patterns(contents: &str) -> Result<Vec<Masks>, Error> {
+  contents
+    .trim_start_matches('\n')
+    .split("\n\n")
+    .map(|block| {
+      let offset = block.as_ptr() as usize - contents.as_ptr() as usize;
+      let lines_before = contents[..offset].matches('\n').count();
+
+      match Grid::parse_with(block, |cell| matches!(cell, '#' | '.').then_some(cell)) {
+        Ok(grid) if grid.rows().max(grid.cols()) > usize::BITS as usize => {
+          Err(Error::unsolvable(format!("patterns of more than {} rows or columns are not supported", usize::BITS)))
+        },
+        Ok(grid) => Ok(transform_pattern_to_masks(&grid)),
+        Err(error) => Err(error.after_lines(lines_before)),
+      }
+    })
+    .collect()
+}
+
+fn is_mask_mirrored(masks: &[usize], offset: usize)-> bool {
+  let mut i = offset;
+  let mut j = offset + 1;
+
+  if j >= masks.len() {
+    return false;
+  }
+
+  loop {
+    if masks[i] != masks[j] {
+      return false;
+    }
+
+    if i == 0 || j == masks.len() - 1 {
+      return true;
+    }
+
+    i -= 1;
+    j += 1;
+  };
+}
+
+fn find_mirror_index(masks: &[usize]) -> Option<usize> {
+  if masks.is_empty() {
+    return None;
+  }
+
+  (0..(masks.len() - 1)).find(|&i| is_mask_mirrored(masks, i))
+}
+
+fn is_power_of_two(value: usize) -> bool {
+  if value == 0 {
+    return false;
+  }
+
+  (value & (value - 1)) == 0
+}
+
+fn is_mask_almost_mirrored(masks: &[usize], offset: usize) -> bool {
+  let mut i = offset;
+  let mut j = offset + 1;
+  let mut has_one_failure = false;
+
+  if j >= masks.len() {
+    return false;
+  }
+
+  loop {
+    if masks[i] != masks[j] {
+      if !has_one_failure {
+        if is_power_of_two(masks[i] ^ masks[j]) {
+          has_one_failure = true;
+        } else {
+          return false;
+        }
+      } else {
+        return false;
+      }
+    }
+
+    if i == 0 || j == masks.len() - 1 {
+      return has_one_failure;
+    }
+
+    i -= 1;
+    j += 1;
+  };
+}
+
+fn find_and_fix_smudge(masks: &[usize]) -> Option<usize> {
+  if masks.is_empty() {
+    return None;
+  }
+  (0..(masks.len() - 1)).find(|&i| is_mask_almost_mirrored(masks, i))
+}
+
+fn summarize_mirrors(patterns: &[Masks]) -> Result<usize, Error> {
+  patterns
+    .iter()
+    .map(|(vertical_masks, horizontal_masks)| {
+      if let Some(horizontal_index) = find_mirror_index(horizontal_masks) {
+        return Ok(horizontal_index + 1);
+      }
+
+      if let Some(vertical_index) = find_mirror_index(vertical_masks) {
+        return Ok((vertical_index + 1) * 100);
+      }
+
+      Err(Error::unsolvable("no mirror found in pattern"))
+    })
+    .sum()
+}
+
+fn summarize_fixed_mirrors(patterns: &[Masks]) -> Result<usize, Error> {
+  patterns
+    .iter()
+    .map(|(vertical_masks, horizontal_masks)| {
+      if let Some(horizontal_index) = find_and_fix_smudge(horizontal_masks) {
+        return Ok(horizontal_index + 1);
+      }
+
+      if let Some(vertical_index) = find_and_fix_smudge(vertical_masks) {
+        return Ok((vertical_index + 1) * 100);
+      }
+
+      Err(Error::unsolvable("no smudge found in pattern"))
+    })
+    .sum()
+}
+
+/// A line between two rows (`false`) or two columns (`true`) of a pattern, after `index`.
+type Line = (bool, usize);
+
+/// Pairs of cells that `line` reflects onto each other.
+fn mirrored_cells(rows: usize, cols: usize, (vertical, index): Line) -> Vec<((usize, usize), (usize, usize))> {
+  let length = if vertical { cols } else { rows };
+  let reach = (index + 1).min(length - index - 1);
+
+  let mut pairs = vec![];
+  for offset in 0..reach {
+    let (near, far) = (index - offset, index + 1 + offset);
+
+    for other in 0..(if vertical { rows } else { cols }) {
+      pairs.push(if vertical { ((other, near), (other, far)) } else { ((near, other), (far, other)) });
+    }
+  }
+
+  pairs
+}
+
+fn generate_pattern(rng: &mut Rng) -> Vec<Vec<bool>> {
+  loop {
+    let (rows, cols) = (rng.range(5..18) as usize, rng.range(5..18) as usize);
+
+    let lines = (0..rows - 1).map(|index| (false, index)).chain((0..cols - 1).map(|index| (true, index))).collect::<Vec<Line>>();
+    let (mirror, smudged) = (*rng.choose(&lines), *rng.choose(&lines));
+    if mirror == smudged {
+      continue;
+    }
+
+    let mut parents = (0..rows * cols).collect::<Vec<usize>>();
+    let root = |parents: &mut Vec<usize>, mut cell: usize| {
+      while parents[cell] != cell {
+        parents[cell] = parents[parents[cell]];
+        cell = parents[cell];
+      }
+      cell
+    };
+
+    for (a, b) in mirrored_cells(rows, cols, mirror).into_iter().chain(mirrored_cells(rows, cols, smudged)) {
+      let (a, b) = (root(&mut parents, a.0 * cols + a.1), root(&mut parents, b.0 * cols + b.1));
+      parents[a] = b;
+    }
+
+    let values = (0..rows * cols).map(|_| rng.chance(1, 2)).collect::<Vec<bool>>();
+    let mut pattern = (0..rows).map(|row| (0..cols).map(|col| values[root(&mut parents, row * cols + col)]).collect()).collect::<Vec<Vec<bool>>>();
+
+    let mirrored = mirrored_cells(rows, cols, mirror).into_iter().flat_map(|(a, b)| [a, b]).collect::<HashSet<(usize, usize)>>();
+    let candidates = mirrored_cells(rows, cols, smudged).into_iter().map(|(a, _)| a).filter(|cell| !mirrored.contains(cell)).collect::<Vec<(usize, usize)>>();
+    if candidates.is_empty() {
+      continue;
+    }
+
+    let (row, col) = *rng.choose(&candidates);
+    pattern[row][col] = !pattern[row][col];
+
+    let differences = |line: Line| mirrored_cells(rows, cols, line).into_iter().filter(|&(a, b)| pattern[a.0][a.1] != pattern[b.0][b.1]).count();
+
+    let is_unique = lines.iter().all(|&line| {
+      match differences(line) {
+        0 => line == mirror,
+        1 => line == smudged,
+        _ => line != mirror && line != smudged,
+      }
+    });
+
+    if is_unique {
+      return pattern;
+    }
+  }
+}
+
+/// `size` patterns of five to seventeen rows and columns. Each has exactly one line of reflection,
+/// and exactly one other line that a single smudge keeps from being one.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  (0..size)
+    .map(|_| {
+      generate_pattern(rng)
+        .iter()
+        .map(|row| row.iter().map(|&rock| if rock { '#' } else { '.' }).collect::<String>() + "\n")
+        .collect::<String>()
+    })
+    .collect::<Vec<String>>()
+    .join("\n")
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+  type Input<'a> = Vec<Masks>;
+
+  type Config = ();
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    parse_patterns(contents)
+  }
+
+  fn part1(patterns: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    summarize_mirrors(patterns).map(|sum| sum.into())
+  }
+
+  fn part2(patterns: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    summarize_fixed_mirrors(patterns).map(|sum| sum.into())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{fuzz::assert_no_panic, generate::assert_solvable, solution::solve};
+
+  use super::*;
+
+  const EXAMPLE: &str = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.##..##.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+";
+
+  #[test]
+  fn part1_example() {
+    let input = Day13::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day13::part1(&input, &()), Ok(Answer::Unsigned(405)));
+  }
+
+  #[test]
+  fn part2_example() {
+    let input = Day13::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day13::part2(&input, &()), Ok(Answer::Unsigned(400)));
+  }
+
+  #[test]
+  fn crlf_input_is_normalized() {
+    let contents = EXAMPLE.replace('\n', "\r\n");
+
+    assert_eq!(solve::<Day13>(&contents, &[1, 2]), vec![Ok(Answer::Unsigned(405)), Ok(Answer::Unsigned(400))]);
+  }
+
+  #[test]
+  fn errors_are_numbered_from_the_start_of_the_input() {
+    assert_eq!(solve::<Day13>("#.\n.#\n\n#.\n#x", &[1]), vec![Err(Error::parse(5, 2, "unexpected character \"x\"").spanning(1))]);
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day13>(generate, 0..20, 10);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day13>(generate, 0..100, 10);
+  }
+}
@@ -0,0 +1,150 @@
+use aoc_common::{Answer, Error, Grid, Solution, config::{self, parse_value}, cycle::find_cycle_hashed, generate::grid, random::Rng};
+
+fn calculate_total_load(platform_map: &Grid<char>) -> usize {
+  let row_count = platform_map.rows();
+
+  platform_map
+    .positions()
+    .filter(|&position| platform_map[position] == 'O')
+    .map(|(row_index, _)| row_count - row_index)
+    .sum::<usize>()
+}
+
+fn tilt_north(mut platform_map: Grid<char>) -> Grid<char> {
+  let row_count = platform_map.rows();
+  let col_count = platform_map.cols();
+
+  for row_index in 0..row_count {
+    for col_index in 0..col_count {
+      if platform_map[(row_index, col_index)] != '.' {
+        continue;
+      }
+
+      for i in (row_index + 1)..row_count {
+        match platform_map[(i, col_index)] {
+          'O' => {
+            platform_map[(row_index, col_index)] = 'O';
+            platform_map[(i, col_index)] = '.';
+            break;
+          },
+          '#' => {
+            break;
+          },
+          _ => {},
+        }
+      }
+    }
+  }
+
+  platform_map
+}
+
+/// Tilts north, west, south and east in turn by rotating the platform a quarter turn after each tilt.
+fn cycle_tilt(mut platform_map: Grid<char>) -> Grid<char> {
+  for _ in 0..4 {
+    platform_map = tilt_north(platform_map).rotate_clockwise();
+  }
+
+  platform_map
+}
+
+/// A `size` by `size` platform of round rocks, cube rocks and empty space.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  grid(rng, size, size, |rng| *rng.choose(&['O', 'O', '#', '.', '.', '.', '.']))
+}
+
+pub struct Config {
+  /// How many spin cycles part 2 runs.
+  pub cycles: usize,
+}
+
+impl Default for Config {
+  fn default() -> Self {
+    Config{ cycles: 1000000000 }
+  }
+}
+
+impl config::Config for Config {
+  const FLAGS: &'static [(&'static str, &'static str)] = &[("cycles", "n")];
+
+  fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+    parse_value(name, value).map(|cycles| self.cycles = cycles)
+  }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+  type Input<'a> = Grid<char>;
+
+  type Config = Config;
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    Grid::parse(contents)
+  }
+
+  fn part1(platform_map: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    let platform_map = tilt_north(platform_map.clone());
+
+    Ok(calculate_total_load(&platform_map).into())
+  }
+
+  fn part2(platform_map: &Self::Input<'_>, config: &Self::Config) -> Result<Answer, Error> {
+    let (cycle, platform_maps) = find_cycle_hashed(platform_map.clone(), |platform_map| cycle_tilt(platform_map.clone()));
+
+    let platform_map = &platform_maps[cycle.reduce(config.cycles)];
+
+    Ok(calculate_total_load(platform_map).into())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{fuzz::assert_no_panic, generate::assert_solvable};
+
+  use super::*;
+
+  const EXAMPLE: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+";
+
+  #[test]
+  fn part1_example() {
+    let input = Day14::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day14::part1(&input, &Config::default()), Ok(Answer::Unsigned(136)));
+  }
+
+  #[test]
+  fn part2_example() {
+    let input = Day14::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day14::part2(&input, &Config::default()), Ok(Answer::Unsigned(64)));
+  }
+
+  #[test]
+  fn load_after_three_cycles() {
+    let input = Day14::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day14::part2(&input, &Config{ cycles: 3 }), Ok(Answer::Unsigned(69)));
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day14>(generate, 0..20, 12);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day14>(generate, 0..100, 12);
+  }
+}
@@ -0,0 +1,173 @@
+use aoc_common::{Answer, Error, Solution, random::Rng};
+
+fn hash(plaintext: &str) -> usize {
+  plaintext
+    .chars()
+    .map(|char| char as usize)
+    .fold(0, |acc, char| ((acc + char) * 17) % 256)
+}
+
+enum Step<'a> {
+  Upsert((&'a str, usize, usize)),
+  Remove((&'a str, usize)),
+}
+
+fn parse_steps<'a>(raw_steps: &[&'a str]) -> Result<Vec<Step<'a>>, Error> {
+  let mut column = 1;
+
+  raw_steps
+    .iter()
+    .map(|&step| {
+      let step_column = column;
+      column = column + step.len() + ",".len();
+
+      if let Some(sign_pos) = step.find('=') {
+        if let Ok(focal_length) = step[(sign_pos + 1)..].parse::<usize>() {
+          Ok(Step::Upsert((&step[0..sign_pos], hash(&step[0..sign_pos]), focal_length)))
+        } else {
+          Err(Error::parse(1, step_column + sign_pos + 1, format!("invalid focal length in step \"{step}\"")).spanning(step.len() - sign_pos - 1))
+        }
+      } else if let Some(sign_pos) = step.find('-') {
+        Ok(Step::Remove((&step[0..sign_pos], hash(&step[0..sign_pos]))))
+      } else {
+        Err(Error::parse(1, step_column, format!("missing \"=\" or \"-\" in step \"{step}\"")).spanning(step.len()))
+      }
+    })
+    .collect()
+}
+
+fn sum_step_hashes(raw_steps: &[&str]) -> usize {
+  raw_steps
+    .iter()
+    .map(|part| hash(part))
+    .sum::<usize>()
+}
+
+fn calculate_focusing_power(raw_steps: &[&str]) -> Result<usize, Error> {
+  let steps = parse_steps(raw_steps)?;
+
+  let mut boxes = vec![Vec::<(&str, usize)>::new(); 256];
+  for step in steps {
+    match step {
+      Step::Upsert((label, hash, focal_length)) => {
+        if let Some(index)  = boxes[hash].iter().position(|current| current.0 == label) {
+          boxes[hash][index].1 = focal_length;
+        } else {
+          boxes[hash].push((label, focal_length));
+        }
+      },
+      Step::Remove((label, hash)) => {
+        let mut index = 0;
+        while index < boxes[hash].len() {
+          if boxes[hash][index].0 == label {
+            boxes[hash].remove(index);
+          } else {
+            index += 1;
+          }
+        }
+      },
+    }
+  }
+
+  let focusing_power = boxes
+    .iter()
+    .enumerate()
+    .map(|(container_index, container)| {
+      container
+        .iter()
+        .enumerate()
+        .map(|(lens_index, (_, focal_length))| (container_index + 1) * (lens_index + 1) * focal_length)
+        .sum::<usize>()
+    })
+    .sum::<usize>();
+
+  Ok(focusing_power)
+}
+
+/// One line of `size` steps over a third as many short labels, so that lenses get replaced and
+/// removed.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  let labels = (0..(size / 3).max(1))
+    .map(|_| (0..rng.range(1..7)).map(|_| char::from(b'a' + rng.below(26) as u8)).collect::<String>())
+    .collect::<Vec<String>>();
+
+  let steps = (0..size)
+    .map(|_| {
+      let label = rng.choose(&labels);
+
+      if rng.chance(1, 3) { format!("{label}-") } else { format!("{label}={}", rng.range(1..10)) }
+    })
+    .collect::<Vec<String>>();
+
+  format!("{}\n", steps.join(","))
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+  type Input<'a> = Vec<&'a str>;
+
+  type Config = ();
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    if contents.contains('\n') {
+      return Err(Error::parse(2, 0, "expected the steps on a single line"));
+    }
+
+    Ok(contents.split(',').collect())
+  }
+
+  fn part1(raw_steps: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    Ok(sum_step_hashes(raw_steps).into())
+  }
+
+  fn part2(raw_steps: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    calculate_focusing_power(raw_steps).map(|focusing_power| focusing_power.into())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{fuzz::assert_no_panic, generate::assert_solvable, solution::solve};
+
+  use super::*;
+
+  const EXAMPLE: &str = "\
+rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+  #[test]
+  fn part1_example() {
+    let input = Day15::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day15::part1(&input, &()), Ok(Answer::Unsigned(1320)));
+  }
+
+  #[test]
+  fn part2_example() {
+    let input = Day15::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day15::part2(&input, &()), Ok(Answer::Unsigned(145)));
+  }
+
+  #[test]
+  fn trailing_newline_is_normalized() {
+    let contents = format!("{EXAMPLE}\n");
+
+    assert_eq!(solve::<Day15>(&contents, &[1, 2]), vec![Ok(Answer::Unsigned(1320)), Ok(Answer::Unsigned(145))]);
+  }
+
+  #[test]
+  fn steps_must_be_on_one_line() {
+    assert_eq!(solve::<Day15>("rn=1,cm-\nqp=3", &[1]), vec![Err(Error::parse(2, 0, "expected the steps on a single line"))]);
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day15>(generate, 0..20, 50);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day15>(generate, 0..100, 50);
+  }
+}
@@ -0,0 +1,308 @@
+use aoc_common::{Answer, Direction, Error, Grid, Solution, generate::grid, random::Rng};
+
+struct Beam {
+  row: usize,
+  col: usize,
+  direction: Direction,
+}
+
+enum NextBeam {
+  Singular(Direction),
+  Split(Direction, Direction),
+}
+
+fn next_beam(grid: &Grid<char>, position: &(usize, usize), direction: &Direction) -> NextBeam {
+  let direction = *direction;
+  let is_vertical = direction == Direction::Up || direction == Direction::Down;
+
+  match grid[*position] {
+    '-' if is_vertical => NextBeam::Split(Direction::Left, Direction::Right),
+    '|' if !is_vertical => NextBeam::Split(Direction::Up, Direction::Down),
+    '/' if is_vertical => NextBeam::Singular(direction.turn_right()),
+    '/' => NextBeam::Singular(direction.turn_left()),
+    '\\' if is_vertical => NextBeam::Singular(direction.turn_left()),
+    '\\' => NextBeam::Singular(direction.turn_right()),
+    _ => NextBeam::Singular(direction),
+  }
+}
+
+fn make_empty_energized_grid(grid: &Grid<char>) -> Grid<u8> {
+  grid.map(|_| 0)
+}
+
+#[derive(Debug)]
+struct TileJump {
+  up: usize,
+  down: usize,
+  left: usize,
+  right: usize,
+}
+
+fn make_jump_table(grid: &Grid<char>) -> Grid<TileJump> {
+  Grid::from_fn(grid.rows(), grid.cols(), |(row_index, col_index)| {
+    let up = (0..row_index)
+      .rev()
+      .find(|&index| grid[(index, col_index)] != '.' && grid[(index, col_index)] != '|')
+      .unwrap_or(0);
+
+    let down = ((row_index + 1)..grid.rows())
+      .find(|&index| grid[(index, col_index)] != '.' && grid[(index, col_index)] != '|')
+      .unwrap_or(grid.rows() - 1);
+
+    let left = (0..col_index)
+      .rev()
+      .find(|&index| grid[(row_index, index)] != '.' && grid[(row_index, index)] != '-')
+      .unwrap_or(0);
+
+    let right = ((col_index + 1)..grid.cols())
+      .find(|&index| grid[(row_index, index)] != '.' && grid[(row_index, index)] != '-')
+      .unwrap_or(grid.cols() - 1);
+
+    TileJump{ up, down, left, right }
+  })
+}
+
+fn move_throught_beam(energized_grid: &mut Grid<u8>, jump_table: &Grid<TileJump>, beam: &Beam) -> Option<Beam> {
+  let jump_table = &jump_table[(beam.row, beam.col)];
+  let bit = beam.direction.bit();
+
+  match beam.direction {
+    Direction::Up => {
+      for i in (jump_table.up..beam.row).rev() {
+        if (energized_grid[(i, beam.col)] & bit) != 0 {
+          return None;
+        }
+
+        energized_grid[(i, beam.col)] |= bit;
+      }
+
+      if beam.row != jump_table.up {
+        return Some(Beam{ row: jump_table.up, col: beam.col, direction: Direction::Up });
+      }
+
+      None
+    },
+    Direction::Down => {
+      for i in (beam.row + 1)..=jump_table.down {
+        if (energized_grid[(i, beam.col)] & bit) != 0 {
+          return None;
+        }
+
+        energized_grid[(i, beam.col)] |= bit;
+      }
+
+      if beam.row != jump_table.down {
+        return Some(Beam{ row: jump_table.down, col: beam.col, direction: Direction::Down });
+      }
+
+      None
+    },
+    Direction::Left => {
+      for i in (jump_table.left..beam.col).rev() {
+        if (energized_grid[(beam.row, i)] & bit) != 0 {
+          return None;
+        }
+
+        energized_grid[(beam.row, i)] |= bit;
+      }
+
+      if beam.col != jump_table.left {
+        return Some(Beam{ row: beam.row, col: jump_table.left, direction: Direction::Left });
+      }
+
+      None
+    },
+    Direction::Right => {
+      for i in (beam.col + 1)..=jump_table.right {
+        if (energized_grid[(beam.row, i)] & bit) != 0 {
+          return None;
+        }
+
+        energized_grid[(beam.row, i)] |= bit;
+      }
+
+      if beam.col != jump_table.right {
+        return Some(Beam{ row: beam.row, col: jump_table.right, direction: Direction::Right });
+      }
+
+      None
+    },
+  }
+}
+
+fn collect_energized_tiles_count(energized_grid: &Grid<u8>) -> usize {
+  energized_grid
+    .positions()
+    .filter(|&position| energized_grid[position] != 0)
+    .count()
+}
+
+fn count_energized_tiles(grid: &Grid<char>) -> usize {
+  let jump_table = make_jump_table(grid);
+  let mut energized_grid = make_empty_energized_grid(grid);
+  let mut beams = vec![];
+
+  {
+    let beam = Beam{ row: 0, col: 0, direction: Direction::Right };
+
+    match next_beam(grid, &(beam.row, beam.col), &beam.direction) {
+      NextBeam::Split(direction0, direction1) => {
+        beams.push(Beam{ row: beam.row, col: beam.col, direction: direction0 });
+        beams.push(Beam{ row: beam.row, col: beam.col, direction: direction1 });
+      },
+      NextBeam::Singular(direction) => {
+        beams.push(Beam{ row: beam.row, col: beam.col, direction });
+      },
+    }
+
+    energized_grid[(beam.row, beam.col)] |= beam.direction.bit();
+  }
+
+  loop {
+    if beams.is_empty() {
+      break;
+    }
+
+    let beam = beams.swap_remove(0);
+
+    if let Some(beam) = move_throught_beam(&mut energized_grid, &jump_table, &beam) {
+
+      match next_beam(grid, &(beam.row, beam.col), &beam.direction) {
+        NextBeam::Split(direction0, direction1) => {
+          beams.push(Beam{ row: beam.row, col: beam.col, direction: direction0 });
+          beams.push(Beam{ row: beam.row, col: beam.col, direction: direction1 });
+        },
+        NextBeam::Singular(direction) => {
+          beams.push(Beam{ row: beam.row, col: beam.col, direction });
+        },
+      }
+    }
+  }
+
+  collect_energized_tiles_count(&energized_grid)
+}
+
+fn find_max_energized_tiles(grid: &Grid<char>) -> usize {
+  let jump_table = make_jump_table(grid);
+
+  let iter0 = (0..grid.rows()).map(|row_index| Beam{ row: row_index, col: 0, direction: Direction::Right });
+  let iter1 = (0..grid.rows()).map(|row_index| Beam{ row: row_index, col: grid.cols() - 1, direction: Direction::Left });
+  let iter2 = (0..grid.cols()).map(|col_index| Beam{ row: 0, col: col_index, direction: Direction::Down });
+  let iter3 = (0..grid.cols()).map(|col_index| Beam{ row: grid.rows() - 1, col: col_index, direction: Direction::Up });
+
+  
+
+  iter0.chain(iter1).chain(iter2).chain(iter3)
+    .map(|beam| {
+      let mut energized_grid = make_empty_energized_grid(grid);
+      let mut beams = vec![];
+
+      match next_beam(grid, &(beam.row, beam.col), &beam.direction) {
+        NextBeam::Split(direction0, direction1) => {
+          beams.push(Beam{ row: beam.row, col: beam.col, direction: direction0 });
+          beams.push(Beam{ row: beam.row, col: beam.col, direction: direction1 });
+        },
+        NextBeam::Singular(direction) => {
+          beams.push(Beam{ row: beam.row, col: beam.col, direction });
+        },
+      }
+
+      energized_grid[(beam.row, beam.col)] |= beam.direction.bit();
+
+      loop {
+        if beams.is_empty() {
+          break;
+        }
+
+        let beam = beams.swap_remove(0);
+
+        if let Some(beam) = move_throught_beam(&mut energized_grid, &jump_table, &beam) {
+          match next_beam(grid, &(beam.row, beam.col), &beam.direction) {
+            NextBeam::Split(direction0, direction1) => {
+              beams.push(Beam{ row: beam.row, col: beam.col, direction: direction0 });
+              beams.push(Beam{ row: beam.row, col: beam.col, direction: direction1 });
+            },
+            NextBeam::Singular(direction) => {
+              beams.push(Beam{ row: beam.row, col: beam.col, direction });
+            },
+          }
+        }
+      }
+
+      collect_energized_tiles_count(&energized_grid)
+    })
+    .max()
+    .unwrap_or(0)
+}
+
+/// A `size` by `size` contraption with mirrors and splitters on about one tile in eight.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  grid(rng, size, size, |rng| if rng.chance(1, 8) { *rng.choose(&['/', '\\', '|', '-']) } else { '.' })
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+  type Input<'a> = Grid<char>;
+
+  type Config = ();
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    match Grid::parse_with(contents, |tile| matches!(tile, '.' | '|' | '-' | '/' | '\\').then_some(tile)) {
+      Ok(grid) if grid.is_empty() => Err(Error::parse(1, 0, "missing contraption")),
+      result => result,
+    }
+  }
+
+  fn part1(grid: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    Ok(count_energized_tiles(grid).into())
+  }
+
+  fn part2(grid: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    Ok(find_max_energized_tiles(grid).into())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{fuzz::assert_no_panic, generate::assert_solvable};
+
+  use super::*;
+
+  const EXAMPLE: &str = "\
+.|...\\....
+|.-.\\.....
+.....|-...
+........|.
+..........
+.........\\
+..../.\\\\..
+.-.-/..|..
+.|....-|.\\
+..//.|....
+";
+
+  #[test]
+  fn part1_example() {
+    let input = Day16::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day16::part1(&input, &()), Ok(Answer::Unsigned(46)));
+  }
+
+  #[test]
+  fn part2_example() {
+    let input = Day16::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day16::part2(&input, &()), Ok(Answer::Unsigned(51)));
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day16>(generate, 0..20, 12);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day16>(generate, 0..100, 12);
+  }
+}
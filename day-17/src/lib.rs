@@ -0,0 +1,153 @@
+use aoc_common::{Answer, Direction, Error, Grid, Solution, generate::grid, graph::dijkstra, random::Rng};
+
+fn parse_heat_loss_map(contents: &str) -> Result<Grid<u32>, Error> {
+  Grid::parse_with(contents, |char| char.to_digit(10))
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Crucible {
+  row: usize,
+  col: usize,
+  direction: Direction,
+  steps_taken_in_direction: u32,
+}
+
+/// Moves on in the current direction while fewer than `max_steps` were taken, or turns left or right
+/// once at least `min_steps` were taken.
+fn find_crucible_moves(heat_loss_map: &Grid<u32>, crucible: &Crucible, min_steps: u32, max_steps: u32) -> Vec<(Crucible, u32)> {
+  [crucible.direction, crucible.direction.turn_left(), crucible.direction.turn_right()]
+    .into_iter()
+    .filter_map(|direction| {
+      let steps_taken_in_direction = if direction == crucible.direction {
+        if crucible.steps_taken_in_direction >= max_steps {
+          return None;
+        }
+
+        crucible.steps_taken_in_direction + 1
+      } else {
+        if crucible.steps_taken_in_direction < min_steps {
+          return None;
+        }
+
+        1
+      };
+
+      let (row, col) = heat_loss_map.offset((crucible.row, crucible.col), direction.delta())?;
+
+      Some((Crucible{ row, col, direction, steps_taken_in_direction }, heat_loss_map[(row, col)]))
+    })
+    .collect()
+}
+
+fn find_shortest_distance(heat_loss_map: &Grid<u32>, min_steps: u32, max_steps: u32) -> Result<u32, Error> {
+  if heat_loss_map.is_empty() {
+    return Ok(0);
+  }
+
+  let goal_row = heat_loss_map.rows() - 1;
+  let goal_col = heat_loss_map.cols() - 1;
+
+  let starts = [Direction::Right, Direction::Down]
+    .map(|direction| Crucible{ row: 0, col: 0, direction, steps_taken_in_direction: 1 });
+
+  let moves = |crucible: &Crucible| find_crucible_moves(heat_loss_map, crucible, min_steps, max_steps);
+
+  let shortest = dijkstra(&moves, starts, |crucible| {
+    crucible.row == goal_row && crucible.col == goal_col && crucible.steps_taken_in_direction >= min_steps
+  });
+
+  match shortest {
+    Some((heat_loss, _)) => Ok(heat_loss),
+    None => Err(Error::unsolvable("no path reaches the factory")),
+  }
+}
+
+/// A `size` by `size` map of heat losses from 1 to 9, at least five blocks across so that the
+/// ultra crucible can reach the factory.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  let size = size.max(5);
+
+  grid(rng, size, size, |rng| char::from(b'1' + rng.below(9) as u8))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+  type Input<'a> = Grid<u32>;
+
+  type Config = ();
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    parse_heat_loss_map(contents)
+  }
+
+  fn part1(heat_loss_map: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    find_shortest_distance(heat_loss_map, 1, 3).map(|heat_loss| heat_loss.into())
+  }
+
+  fn part2(heat_loss_map: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    find_shortest_distance(heat_loss_map, 4, 10).map(|heat_loss| heat_loss.into())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{fuzz::assert_no_panic, generate::assert_solvable};
+
+  use super::*;
+
+  const EXAMPLE_1: &str = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+";
+
+  const EXAMPLE_2: &str = "\
+111111111111
+999999999991
+999999999991
+999999999991
+999999999991
+";
+
+  #[test]
+  fn part1_example() {
+    let input = Day17::parse(EXAMPLE_1).unwrap();
+
+    assert_eq!(Day17::part1(&input, &()), Ok(Answer::Unsigned(102)));
+  }
+
+  #[test]
+  fn part2_example() {
+    let input = Day17::parse(EXAMPLE_1).unwrap();
+
+    assert_eq!(Day17::part2(&input, &()), Ok(Answer::Unsigned(94)));
+  }
+
+  #[test]
+  fn part2_example_with_long_path() {
+    let input = Day17::parse(EXAMPLE_2).unwrap();
+
+    assert_eq!(Day17::part2(&input, &()), Ok(Answer::Unsigned(71)));
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day17>(generate, 0..20, 12);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day17>(generate, 0..100, 12);
+  }
+}
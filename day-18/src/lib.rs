@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+
+use aoc_common::{Answer, Direction, Error, Solution, generate::rectilinear_loop, geometry::{Point, is_self_intersecting, lattice_points}, random::Rng};
+
+fn parse_dig_plan(contents: &str) -> Result<Vec<(Direction, usize, u32)>, Error> {
+  contents
+    .lines()
+    .enumerate()
+    .map(|(index, line)| {
+      let line_number = index + 1;
+      let mut parts = line.split_whitespace();
+
+      let direction = match parts.next() {
+        Some(direction) => direction,
+        None => return Err(Error::parse(line_number, 0, "missing direction")),
+      };
+      let mut chars = direction.chars();
+      let direction = match (chars.next().and_then(Direction::from_char), chars.next()) {
+        (Some(parsed), None) => parsed,
+        _ => return Err(Error::parse_at(line_number, line, direction, format!("unexpected direction \"{direction}\""))),
+      };
+
+      let steps = match parts.next() {
+        Some(steps) => steps,
+        None => return Err(Error::parse(line_number, 0, "missing steps")),
+      };
+      let steps = match steps.parse::<usize>() {
+        Ok(value) => value,
+        Err(error) => return Err(Error::parse_at(line_number, line, steps, format!("invalid steps \"{steps}\": {error}"))),
+      };
+
+      let color = match parts.next() {
+        Some(color) => color,
+        None => return Err(Error::parse(line_number, 0, "missing color")),
+      };
+      if color.len() != "(#xxxxxx)".len() || !color.is_ascii() || !color.starts_with("(#") || !color.ends_with(')') {
+        return Err(Error::parse_at(line_number, line, color, format!("expected color in the form \"(#xxxxxx)\", found \"{color}\"")));
+      }
+
+      let red = match u32::from_str_radix(&color[2..4], 16) {
+        Ok(red) => red,
+        Err(error) => return Err(Error::parse_at(line_number, line, &color[2..4], format!("invalid color: {error}"))),
+      };
+      let blue = match u32::from_str_radix(&color[4..6], 16) {
+        Ok(blue) => blue,
+        Err(error) => return Err(Error::parse_at(line_number, line, &color[4..6], format!("invalid color: {error}"))),
+      };
+      let green = match u32::from_str_radix(&color[6..8], 16) {
+        Ok(green) => green,
+        Err(error) => return Err(Error::parse_at(line_number, line, &color[6..8], format!("invalid color: {error}"))),
+      };
+
+      let color = red << 16 | blue << 8 | green;
+
+      Ok((direction, steps, color))
+    })
+    .collect()
+}
+
+fn fix_dig_plan(mut dig_plan: Vec<(Direction, usize, u32)>) -> Result<Vec<(Direction, usize, u32)>, Error> {
+  for entry in dig_plan.iter_mut() {
+    let direction = match entry.2 & 0xf {
+      0 => Direction::Right,
+      1 => Direction::Down,
+      2 => Direction::Left,
+      3 => Direction::Up,
+      _ => return Err(Error::unsolvable(format!("color {:#08x} does not encode a direction", entry.2))),
+    };
+
+    let steps = (entry.2 >> 4) as usize;
+
+    entry.0 = direction;
+    entry.1 = steps;
+  }
+
+  Ok(dig_plan)
+}
+
+fn trace_trench(dig_plan: &[(Direction, usize, u32)]) -> Vec<Point> {
+  let mut vertices = vec![(0, 0)];
+  let mut current_position = (0i64, 0i64);
+
+  for (direction, steps, _) in dig_plan {
+    let (row, col) = direction.delta();
+
+    current_position.0 += row as i64 * *steps as i64;
+    current_position.1 += col as i64 * *steps as i64;
+
+    vertices.push(current_position);
+  }
+
+  vertices
+}
+
+/// Plans with more turns than this are trusted not to cross themselves, since the check compares
+/// every pair of edges.
+const MAX_CHECKED_TURNS: usize = 5000;
+
+/// Cubic meters held by the lagoon: the trench itself plus everything it encloses.
+fn calculate_lagoon_volume(dig_plan: &[(Direction, usize, u32)]) -> Result<u64, Error> {
+  let mut vertices = trace_trench(dig_plan);
+
+  if vertices.last() != Some(&(0, 0)) {
+    return Err(Error::unsolvable("dig plan does not return to its start"));
+  }
+
+  vertices.pop();
+
+  if vertices.len() <= MAX_CHECKED_TURNS && is_self_intersecting(&vertices) {
+    return Err(Error::unsolvable("dig plan crosses itself"));
+  }
+
+  Ok(lattice_points(&vertices))
+}
+
+/// Moves the distinct `x` and `y` coordinates of the vertices apart by random gaps below `gap`,
+/// keeping their order, which keeps a simple polygon simple.
+fn stretch(rng: &mut Rng, vertices: &[Point], gap: i64) -> Vec<Point> {
+  let mut axes = [vertices.iter().map(|vertex| vertex.0).collect::<Vec<i64>>(), vertices.iter().map(|vertex| vertex.1).collect()];
+
+  let stretched = axes.each_mut().map(|values| {
+    values.sort();
+    values.dedup();
+
+    let mut position = 0;
+    values
+      .iter()
+      .map(|&value| {
+        position += rng.range(1..gap.max(2));
+        (value, position)
+      })
+      .collect::<HashMap<i64, i64>>()
+  });
+
+  vertices.iter().map(|&(x, y)| (stretched[0][&x], stretched[1][&y])).collect()
+}
+
+/// A dig plan around a simple lagoon with about `size` turns. The colors trace the same shape
+/// stretched to edges of up to `0xfffff` meters.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  let nodes = (size / 4).max(1);
+  let corners = rectilinear_loop(rng, nodes, nodes);
+
+  let plan = stretch(rng, &corners, 10);
+  let colors = stretch(rng, &corners, 0xfffff / (2 * nodes as i64));
+
+  let edge = |vertices: &[Point], index: usize| {
+    let ((x, y), (nx, ny)) = (vertices[index], vertices[(index + 1) % vertices.len()]);
+
+    match (nx - x, ny - y) {
+      (dx, 0) if dx > 0 => ('R', 0, dx),
+      (dx, 0) => ('L', 2, -dx),
+      (0, dy) if dy > 0 => ('D', 1, dy),
+      (_, dy) => ('U', 3, -dy),
+    }
+  };
+
+  let mut contents = String::new();
+  for index in 0..corners.len() {
+    let (direction, _, steps) = edge(&plan, index);
+    let (_, code, distance) = edge(&colors, index);
+
+    contents.push_str(&format!("{direction} {steps} (#{distance:05x}{code})\n"));
+  }
+
+  contents
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+  type Input<'a> = Vec<(Direction, usize, u32)>;
+
+  type Config = ();
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    parse_dig_plan(contents)
+  }
+
+  fn part1(dig_plan: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    calculate_lagoon_volume(dig_plan).map(|volume| volume.into())
+  }
+
+  fn part2(dig_plan: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    let dig_plan = fix_dig_plan(dig_plan.clone())?;
+
+    calculate_lagoon_volume(&dig_plan).map(|volume| volume.into())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{fuzz::assert_no_panic, generate::assert_solvable};
+
+  use super::*;
+
+  const EXAMPLE: &str = "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+";
+
+  #[test]
+  fn part1_example() {
+    let input = Day18::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day18::part1(&input, &()), Ok(Answer::Unsigned(62)));
+  }
+
+  #[test]
+  fn part2_example() {
+    let input = Day18::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day18::part2(&input, &()), Ok(Answer::Unsigned(952408144115)));
+  }
+
+  #[test]
+  fn invalid_steps_are_a_parse_error() {
+    let result = Day18::parse("R 6 (#70c710)\nD x (#0dc571)");
+
+    assert!(matches!(result, Err(Error::Parse{ line: 2, column: 3, .. })));
+  }
+
+  #[test]
+  fn crossing_trench_is_unsolvable() {
+    let input = Day18::parse("R 2 (#000000)\nD 2 (#000000)\nL 1 (#000000)\nU 3 (#000000)\nL 1 (#000000)\nD 1 (#000000)").unwrap();
+
+    assert!(matches!(Day18::part1(&input, &()), Err(Error::Unsolvable(_))));
+  }
+
+  #[test]
+  fn generated_trenches_do_not_cross() {
+    for seed in 0..20 {
+      let dig_plan = Day18::parse(&generate(&mut Rng::new(seed), 20)).unwrap();
+
+      assert!(!is_self_intersecting(&trace_trench(&dig_plan)), "seed {seed}");
+      assert!(!is_self_intersecting(&trace_trench(&fix_dig_plan(dig_plan).unwrap())), "seed {seed}");
+    }
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day18>(generate, 0..20, 20);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day18>(generate, 0..100, 20);
+  }
+}
@@ -0,0 +1,671 @@
+use std::collections::HashMap;
+
+use aoc_common::{Answer, Error, Solution, graph::topological_sort, interval::{Hyperrectangle, Interval}, parser::{Failure, Parser, alt, and_then, delimited, identifier, map, number, pair, parse_line, preceded, separated_list1, separated_pair, tag, value, ws}, random::Rng};
+
+#[derive(Copy, Clone)]
+enum Op {
+  Greater,
+  Lesser,
+}
+
+struct RejectCondition {
+  value_index: usize,
+  compare_op: Op,
+  test_value: usize,
+}
+
+struct AcceptCondition {
+  value_index: usize,
+  compare_op: Op,
+  test_value: usize,
+}
+
+struct RedirectCondition<'a> {
+  value_index: usize,
+  compare_op: Op,
+  test_value: usize,
+  redirect_to: &'a str,
+}
+
+enum Rule<'a> {
+  RejectCondition(RejectCondition),
+  AcceptCondition(AcceptCondition),
+  RedirectCondition(RedirectCondition<'a>),
+  Reject,
+  Accept,
+  Redirect(&'a str),
+}
+
+struct Machine<'a> {
+  workflows: Vec<(&'a str, Vec<Rule<'a>>)>,
+  indices: HashMap<&'a str, usize>,
+}
+
+/// The index of the rating category `name`.
+fn rating(name: &str) -> Result<usize, Failure<'_>> {
+  match name {
+    "x" => Ok(0),
+    "m" => Ok(1),
+    "a" => Ok(2),
+    "s" => Ok(3),
+    _ => Err(Failure::invalid(name, format!("unknown category \"{name}\""))),
+  }
+}
+
+fn rule<'a>() -> impl Parser<'a, Rule<'a>> {
+  let compare_op = alt(value(tag(">"), Op::Greater), value(tag("<"), Op::Lesser));
+
+  let condition = map(
+    pair(and_then(pair(identifier, compare_op), |(name, compare_op)| rating(name).map(|index| (index, compare_op))), pair(number::<usize>(), preceded(tag(":"), identifier))),
+    |((value_index, compare_op), (test_value, redirect_to))| {
+      match redirect_to {
+        "A" => Rule::AcceptCondition(AcceptCondition{ value_index, compare_op, test_value }),
+        "R" => Rule::RejectCondition(RejectCondition{ value_index, compare_op, test_value }),
+        _ => Rule::RedirectCondition(RedirectCondition{ value_index, compare_op, test_value, redirect_to }),
+      }
+    },
+  );
+
+  let target = map(identifier, |target| {
+    match target {
+      "A" => Rule::Accept,
+      "R" => Rule::Reject,
+      _ => Rule::Redirect(target),
+    }
+  });
+
+  alt(condition, target)
+}
+
+fn parse_machine<'a>(contents: &'a str) -> Result<Machine<'a>, Error> {
+  let workflow = pair(identifier, delimited(tag("{"), separated_list1(rule(), tag(",")), tag("}")));
+
+  let mut workflows = vec![];
+
+  for (index, line) in contents.lines().enumerate() {
+    if line.is_empty() || line.starts_with("{") {
+      break;
+    }
+
+    workflows.push(parse_line(index + 1, line, ws(&workflow))?);
+  }
+
+  let mut indices = HashMap::new();
+
+  for (index, &(rule_name, _)) in workflows.iter().enumerate() {
+    indices.insert(rule_name, index);
+  }
+
+
+  Ok(Machine{ workflows, indices })
+}
+
+/// Refuses workflows that can redirect back to themselves, which would send a part around forever.
+fn check_acyclic(machine: &Machine) -> Result<(), Error> {
+  let redirects = |&index: &usize| {
+    machine.workflows[index].1
+      .iter()
+      .filter_map(|rule| {
+        match rule {
+          Rule::RedirectCondition(redirect_condition) => machine.indices.get(redirect_condition.redirect_to).copied(),
+          Rule::Redirect(workflow_name) => machine.indices.get(workflow_name).copied(),
+          _ => None,
+        }
+      })
+      .collect::<Vec<usize>>()
+  };
+
+  match topological_sort(&redirects, 0..machine.workflows.len()) {
+    Some(_) => Ok(()),
+    None => Err(Error::unsolvable("the workflows redirect in a cycle")),
+  }
+}
+
+fn optimize_workflow_resolve_redirect_rules(machine: &mut Machine, index: usize) -> bool {
+  for i in 0..machine.workflows[index].1.len() {
+    if let Rule::Redirect(target_rule_name) = machine.workflows[index].1[i] {
+      if let Some(&target_index) = machine.indices.get(target_rule_name) {
+        if machine.workflows[target_index].1.len() == 1 {
+          match machine.workflows[target_index].1[0] {
+            Rule::Accept => {
+              machine.workflows[index].1[i] = Rule::Accept;
+            },
+            Rule::Reject => {
+              machine.workflows[index].1[i] = Rule::Reject;
+            },
+            _ => {},
+          }
+        }
+      }
+    }
+  }
+
+  false
+}
+
+fn optimize_workflow_resolve_redirect_condition_rules(machine: &mut Machine, index: usize) -> bool {
+  for i in 0..machine.workflows[index].1.len() {
+    if let Rule::RedirectCondition(redirect_condition) = &machine.workflows[index].1[i] {
+      if let Some(&target_index) = machine.indices.get(redirect_condition.redirect_to) {
+        if machine.workflows[target_index].1.len() == 1 {
+          match machine.workflows[target_index].1[0] {
+            Rule::Accept => {
+              machine.workflows[index].1[i] = Rule::AcceptCondition(AcceptCondition{
+                value_index: redirect_condition.value_index,
+                compare_op: redirect_condition.compare_op,
+                test_value: redirect_condition.test_value,
+              });
+            },
+            Rule::Reject => {
+              machine.workflows[index].1[i] = Rule::RejectCondition(RejectCondition{
+                value_index: redirect_condition.value_index,
+                compare_op: redirect_condition.compare_op,
+                test_value: redirect_condition.test_value,
+              });
+            },
+            _ => {},
+          }
+        }
+      }
+    }
+  }
+
+  false
+}
+
+fn optimize_workflow_with_only_accept_rules(machine: &mut Machine, index: usize) -> bool {
+  let (_, rules) = &machine.workflows[index];
+
+  if rules.len() <= 1 {
+    return false;
+  }
+
+  let has_only_accept_rules = rules.iter().all(|rule| matches!(rule, Rule::AcceptCondition(_) | Rule::Accept));
+
+  if !has_only_accept_rules {
+    return false;
+  }
+
+  machine.workflows[index].1.clear();
+  machine.workflows[index].1.push(Rule::Accept);
+
+  true
+}
+
+fn optimize_workflow_with_only_reject_rules(machine: &mut Machine, index: usize) -> bool {
+  let (_, rules) = &machine.workflows[index];
+
+  if rules.len() <= 1 {
+    return false;
+  }
+
+  let has_only_reject_rules = rules.iter().all(|rule| matches!(rule, Rule::RejectCondition(_) | Rule::Reject));
+
+  if !has_only_reject_rules {
+    return false;
+  }
+
+  machine.workflows[index].1.clear();
+  machine.workflows[index].1.push(Rule::Reject);
+
+  true
+}
+
+fn optimize_workflows(mut machine: Machine) -> Machine {
+  let mut workflow_was_changed = true;
+
+  while workflow_was_changed {
+    workflow_was_changed = false;
+
+    for index in 0..machine.workflows.len() {
+      workflow_was_changed |= optimize_workflow_resolve_redirect_rules(&mut machine, index);
+      workflow_was_changed |= optimize_workflow_resolve_redirect_condition_rules(&mut machine, index);
+      workflow_was_changed |= optimize_workflow_with_only_accept_rules(&mut machine, index);
+      workflow_was_changed |= optimize_workflow_with_only_reject_rules(&mut machine, index);
+    }
+  }
+
+  machine
+}
+
+fn parse_parts(contents: &str) -> Result<Vec<[usize; 4]>, Error> {
+  let part_set = delimited(tag("{"), separated_list1(separated_pair(and_then(identifier, rating), tag("="), number::<usize>()), tag(",")), tag("}"));
+
+  let mut parts = vec![];
+
+  for (index, line) in contents.lines().enumerate() {
+    if line.is_empty() || !line.starts_with("{") || !line.ends_with("}") {
+      continue;
+    }
+
+    let ratings = parse_line(index + 1, line, &part_set)?;
+
+    let mut part = [0; 4];
+    for (index, value) in ratings {
+      part[index] = value;
+    }
+
+    parts.push(part);
+  }
+
+  Ok(parts)
+}
+
+
+fn process_part_set(machine: &Machine, part_set: &[usize; 4]) -> Result<bool, Error> {
+  let mut workflow_index = match machine.indices.get("in") {
+    Some(&index) => index,
+    None => return Err(Error::unsolvable("no \"in\" workflow")),
+  };
+
+  loop {
+    let rules = &machine.workflows[workflow_index].1;
+
+    'rules_loop: for rule in rules {
+      match rule {
+        Rule::RejectCondition(reject_condition) => {
+          match reject_condition.compare_op {
+            Op::Greater => {
+              if part_set[reject_condition.value_index] > reject_condition.test_value {
+                return Ok(false);
+              }
+            },
+            Op::Lesser => {
+              if part_set[reject_condition.value_index] < reject_condition.test_value {
+                return Ok(false);
+              }
+            },
+          }
+        },
+        Rule::AcceptCondition(accept_condition) => {
+          match accept_condition.compare_op {
+            Op::Greater => {
+              if part_set[accept_condition.value_index] > accept_condition.test_value {
+                return Ok(true);
+              }
+            },
+            Op::Lesser => {
+              if part_set[accept_condition.value_index] < accept_condition.test_value {
+                return Ok(true);
+              }
+            },
+          }
+        },
+        Rule::RedirectCondition(redirect_condition) => {
+          let should_redirect = match redirect_condition.compare_op {
+            Op::Greater => part_set[redirect_condition.value_index] > redirect_condition.test_value,
+            Op::Lesser => part_set[redirect_condition.value_index] < redirect_condition.test_value,
+          };
+
+          if should_redirect {
+            workflow_index = match machine.indices.get(redirect_condition.redirect_to) {
+              Some(&index) => index,
+              None => return Err(Error::unsolvable(format!("no workflow \"{}\"", redirect_condition.redirect_to))),
+            };
+
+            break 'rules_loop;
+          }
+        },
+        Rule::Reject => {
+          return Ok(false);
+        },
+        Rule::Accept => {
+          return Ok(true);
+        },
+        Rule::Redirect(workflow_name) => {
+          workflow_index = match machine.indices.get(workflow_name) {
+            Some(&index) => index,
+            None => return Err(Error::unsolvable(format!("no workflow \"{workflow_name}\""))),
+          };
+
+          break 'rules_loop;
+        },
+      }
+    }
+  }
+}
+
+
+type Ratings = Hyperrectangle<4>;
+
+/// The ratings matching `value_index` compared against `test_value`, and the rest.
+fn split_ratings(ratings: &Ratings, value_index: usize, compare_op: Op, test_value: usize) -> (Option<Ratings>, Option<Ratings>) {
+  let test_value = test_value as i64;
+
+  match compare_op {
+    Op::Greater => {
+      let (rest, matching) = ratings.split_at(value_index, test_value + 1);
+
+      (matching, rest)
+    },
+    Op::Lesser => ratings.split_at(value_index, test_value),
+  }
+}
+
+fn find_possible_distinct_combinations<'a>(machine: &Machine<'a>, workflow_index: usize, ratings: Ratings) -> Result<u128, Error> {
+  let rules = &machine.workflows[workflow_index].1;
+
+  let mut ratings = ratings;
+
+  let mut sum = 0;
+
+  for rule in rules {
+    match rule {
+      Rule::RejectCondition(reject_condition) => {
+        let (_, left_ratings) = split_ratings(&ratings, reject_condition.value_index, reject_condition.compare_op, reject_condition.test_value);
+
+        ratings = match left_ratings {
+          Some(left_ratings) => left_ratings,
+          None => return Ok(sum),
+        };
+      },
+      Rule::AcceptCondition(accept_condition) => {
+        let (accepted_ratings, left_ratings) = split_ratings(&ratings, accept_condition.value_index, accept_condition.compare_op, accept_condition.test_value);
+
+        if let Some(accepted_ratings) = accepted_ratings {
+          sum += accepted_ratings.volume();
+        }
+
+        ratings = match left_ratings {
+          Some(left_ratings) => left_ratings,
+          None => return Ok(sum),
+        };
+      },
+      Rule::RedirectCondition(redirect_condition) => {
+        let (redirected_ratings, left_ratings) = split_ratings(&ratings, redirect_condition.value_index, redirect_condition.compare_op, redirect_condition.test_value);
+
+        if let Some(redirected_ratings) = redirected_ratings {
+          let partial_sum = match machine.indices.get(redirect_condition.redirect_to) {
+            Some(&next_workflow_index) => find_possible_distinct_combinations(machine, next_workflow_index, redirected_ratings)?,
+            None => return Err(Error::unsolvable(format!("no workflow \"{}\"", redirect_condition.redirect_to))),
+          };
+
+          sum += partial_sum;
+        }
+
+        ratings = match left_ratings {
+          Some(left_ratings) => left_ratings,
+          None => return Ok(sum),
+        };
+      },
+      Rule::Reject => {
+        return Ok(sum);
+      },
+      Rule::Accept => {
+        return Ok(sum + ratings.volume());
+      },
+      Rule::Redirect(workflow_name) => {
+        match machine.indices.get(workflow_name) {
+          Some(&next_workflow_index) => match find_possible_distinct_combinations(machine, next_workflow_index, ratings) {
+            Ok(partial_sum) => return Ok(sum + partial_sum),
+            Err(error) => return Err(error),
+          },
+          None => return Err(Error::unsolvable(format!("no workflow \"{workflow_name}\""))),
+        }
+      },
+    }
+  }
+
+  Ok(sum)
+}
+
+pub struct System<'a> {
+  machine: Machine<'a>,
+  parts: Vec<[usize; 4]>,
+}
+
+/// A target for a rule in workflow `index`: mostly `A` or `R`, otherwise a later workflow.
+fn generate_target(rng: &mut Rng, names: &[String], index: usize) -> String {
+  if index + 1 == names.len() || rng.chance(2, 3) {
+    rng.choose(&["A", "R"]).to_string()
+  } else {
+    names[rng.range(index as i64 + 1..names.len() as i64) as usize].clone()
+  }
+}
+
+/// Up to `size` workflows that only redirect to later ones, so every part ends up accepted or
+/// rejected, followed by `size` parts. Thresholds come from a pool of `size` values so that rules
+/// often test the same one.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  let count = rng.range(1..size.max(1) as i64 + 1) as usize;
+
+  let mut names = vec![String::from("in")];
+  while names.len() < count {
+    let name = (0..rng.range(2..4)).map(|_| char::from(b'a' + rng.below(26) as u8)).collect::<String>();
+
+    if !names.contains(&name) {
+      names.push(name);
+    }
+  }
+
+  let thresholds = (0..size.max(1)).map(|_| rng.range(0..4002)).collect::<Vec<i64>>();
+
+  let mut workflows = vec![];
+  for index in 0..names.len() {
+    let mut rules = vec![];
+
+    for _ in 0..rng.range(0..4) {
+      let rating = rng.choose(&['x', 'm', 'a', 's']);
+      let compare_op = rng.choose(&['<', '>']);
+      let threshold = rng.choose(&thresholds);
+
+      rules.push(format!("{rating}{compare_op}{threshold}:{}", generate_target(rng, &names, index)));
+    }
+
+    rules.push(generate_target(rng, &names, index));
+
+    workflows.push(format!("{}{{{}}}\n", names[index], rules.join(",")));
+  }
+
+  rng.shuffle(&mut workflows);
+
+  let mut contents = workflows.concat();
+  contents.push('\n');
+
+  for _ in 0..size {
+    let ratings = (0..4).map(|_| (*rng.choose(&thresholds) + rng.range(-1..2)).clamp(1, 4000)).collect::<Vec<i64>>();
+
+    contents.push_str(&format!("{{x={},m={},a={},s={}}}\n", ratings[0], ratings[1], ratings[2], ratings[3]));
+  }
+
+  contents
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+  type Input<'a> = System<'a>;
+
+  type Config = ();
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    let machine = parse_machine(contents)?;
+    check_acyclic(&machine)?;
+    let parts = parse_parts(contents)?;
+
+    let machine = optimize_workflows(machine);
+
+    Ok(System{ machine, parts })
+  }
+
+  fn part1(system: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    let processed_parts = system.parts
+      .iter()
+      .map(|part_set| {
+        match process_part_set(&system.machine, part_set) {
+          Ok(result) => Ok((part_set, result)),
+          Err(error) => Err(error),
+        }
+      })
+      .collect::<Result<Vec<(&[usize; 4], bool)>, Error>>();
+
+    let processed_parts = processed_parts?;
+
+    let sum = processed_parts
+      .iter()
+      .filter(|(_, result)| *result)
+      .map(|(part_set, _)| part_set[0] + part_set[1] + part_set[2] + part_set[3])
+      .sum::<usize>();
+
+    Ok(sum.into())
+  }
+
+  fn part2(system: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    let machine = &system.machine;
+
+    let workflow_index = match machine.indices.get("in") {
+      Some(&workflow_index) => workflow_index,
+      None => return Err(Error::unsolvable("no \"in\" workflow")),
+    };
+
+    let combinations = find_possible_distinct_combinations(machine, workflow_index, Ratings::new([Interval::new(1, 4001); 4]))?;
+
+    match u64::try_from(combinations) {
+      Ok(combinations) => Ok(combinations.into()),
+      Err(_) => Err(Error::unsolvable("too many combinations")),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{differential::assert_agree, fuzz::assert_no_panic, generate::assert_solvable};
+
+  use super::*;
+
+  fn condition(rule: &Rule) -> Option<(usize, Op, usize)> {
+    match rule {
+      Rule::RejectCondition(condition) => Some((condition.value_index, condition.compare_op, condition.test_value)),
+      Rule::AcceptCondition(condition) => Some((condition.value_index, condition.compare_op, condition.test_value)),
+      Rule::RedirectCondition(condition) => Some((condition.value_index, condition.compare_op, condition.test_value)),
+      _ => None,
+    }
+  }
+
+  /// Both answers from the workflows as written. Part 2 cuts each rating at every threshold the
+  /// rules test and runs one part from each resulting block through the machine.
+  fn solve_unoptimized(contents: &str) -> (Result<Answer, Error>, Result<Answer, Error>) {
+    let machine = parse_machine(contents).unwrap();
+    let parts = parse_parts(contents).unwrap();
+
+    let accepted = parts
+      .iter()
+      .filter(|part| process_part_set(&machine, part).unwrap())
+      .map(|part| part.iter().sum::<usize>())
+      .sum::<usize>();
+
+    let mut cuts = [vec![1, 4001], vec![1, 4001], vec![1, 4001], vec![1, 4001]];
+    for (_, rules) in machine.workflows.iter() {
+      for (value_index, compare_op, test_value) in rules.iter().filter_map(condition) {
+        let cut = match compare_op {
+          Op::Greater => test_value + 1,
+          Op::Lesser => test_value,
+        };
+
+        cuts[value_index].push(cut.clamp(1, 4001));
+      }
+    }
+
+    let blocks = cuts.map(|mut cuts| {
+      cuts.sort();
+      cuts.dedup();
+      cuts.windows(2).map(|window| (window[0], window[1] - window[0])).collect::<Vec<(usize, usize)>>()
+    });
+
+    let mut combinations = 0u64;
+    for &(x, x_count) in blocks[0].iter() {
+      for &(m, m_count) in blocks[1].iter() {
+        for &(a, a_count) in blocks[2].iter() {
+          for &(s, s_count) in blocks[3].iter() {
+            if process_part_set(&machine, &[x, m, a, s]).unwrap() {
+              combinations += (x_count * m_count * a_count * s_count) as u64;
+            }
+          }
+        }
+      }
+    }
+
+    (Ok(accepted.into()), Ok(combinations.into()))
+  }
+
+  #[test]
+  fn optimized_workflows_agree_with_unoptimized() {
+    let fast = |contents: &str| {
+      let system = Day19::parse(contents).unwrap();
+
+      (Day19::part1(&system, &()), Day19::part2(&system, &()))
+    };
+
+    assert_agree(0..1000, |rng| generate(rng, 5), fast, solve_unoptimized);
+  }
+
+  const EXAMPLE: &str = "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}
+";
+
+  #[test]
+  fn part1_example() {
+    let input = Day19::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day19::part1(&input, &()), Ok(Answer::Unsigned(19114)));
+  }
+
+  #[test]
+  fn part2_example() {
+    let input = Day19::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day19::part2(&input, &()), Ok(Answer::Unsigned(167409079868000)));
+  }
+
+  #[test]
+  fn unknown_categories_point_at_the_category() {
+    let result = Day19::parse("px{a<2006:qkq,rfg}\n  pv{q>1716:R,A}\n\n{x=1,m=2,a=3,s=4}");
+    assert_eq!(result.err(), Some(Error::parse(2, 6, "unknown category \"q\"").spanning(1)));
+
+    let result = Day19::parse("in{x<5:A,R}\n\n{x=1,m=2,a=3,s=4}\n{x=1,mm=2,a=3,s=4}");
+    assert_eq!(result.err(), Some(Error::parse(4, 6, "unknown category \"mm\"").spanning(2)));
+  }
+
+  #[test]
+  fn missing_workflows_are_named() {
+    let system = Day19::parse("in{x<5:zz,ab}\n\n{x=1,m=2,a=3,s=4}").unwrap();
+    assert_eq!(Day19::part1(&system, &()), Err(Error::unsolvable("no workflow \"zz\"")));
+
+    let system = Day19::parse("in{x>5:A,ab}\n\n{x=1,m=2,a=3,s=4}").unwrap();
+    assert_eq!(Day19::part2(&system, &()), Err(Error::unsolvable("no workflow \"ab\"")));
+  }
+
+  #[test]
+  fn cyclic_workflows_are_refused() {
+    let cycle = Error::unsolvable("the workflows redirect in a cycle");
+
+    assert_eq!(Day19::parse("in{in}\n\n{x=1,m=2,a=3,s=4}").err(), Some(cycle.clone()));
+    assert_eq!(Day19::parse("in{ab}\nab{ba}\nba{ab}\n\n{x=1,m=2,a=3,s=4}").err(), Some(cycle));
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day19>(generate, 0..20, 20);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day19>(generate, 0..100, 20);
+  }
+}
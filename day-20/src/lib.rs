@@ -0,0 +1,702 @@
+use std::collections::{HashMap, VecDeque, HashSet};
+
+use aoc_common::{Answer, Error, Solution, config::{self, parse_value}, cycle::{Cycle, brent_within, first_common_hit}, graph::bfs_distances, parser::{alt, identifier, map, pair, parse_line, separated_list0, separated_pair, tag, ws}, random::Rng};
+
+/// A single press sending more pulses than this is taken to never settle.
+const PULSE_LIMIT: usize = 1 << 16;
+
+/// Subsections whose state takes longer than this many presses to repeat are given up on.
+const PRESS_LIMIT: usize = 1 << 16;
+
+#[derive(Clone, PartialEq)]
+struct BroadcasterModule<'a> {
+  name: &'a str,
+  outputs: Vec<&'a str>,
+}
+
+#[derive(Clone, PartialEq)]
+struct FlipFlowModule<'a> {
+  name: &'a str,
+  outputs: Vec<&'a str>,
+  enabled: bool,
+}
+
+#[derive(Clone, PartialEq)]
+struct ConjunctionModule<'a> {
+  name: &'a str,
+  remembered_signals: HashMap<&'a str, bool>,
+  outputs: Vec<&'a str>,
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Clone, PartialEq)]
+enum Module<'a> {
+  BroadcasterModule(BroadcasterModule<'a>),
+  FlipFlowModule(FlipFlowModule<'a>),
+  ConjunctionModule(ConjunctionModule<'a>),
+}
+
+fn parse_module<'a>(line_number: usize, line: &'a str) -> Result<Module<'a>, Error> {
+  let kind = alt(pair(tag("%"), identifier), alt(pair(tag("&"), identifier), pair(tag(""), identifier)));
+
+  let outputs = separated_list0(ws(identifier), ws(tag(",")));
+
+  let module = map(separated_pair(kind, ws(tag("->")), outputs), |((prefix, name), outputs)| {
+    match prefix {
+      "%" => Module::FlipFlowModule(FlipFlowModule{ name, outputs, enabled: false }),
+      "&" => Module::ConjunctionModule(ConjunctionModule{ name, remembered_signals: HashMap::new(), outputs }),
+      _ => Module::BroadcasterModule(BroadcasterModule{ name, outputs }),
+    }
+  });
+
+  parse_line(line_number, line, ws(module))
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Processor<'a> {
+  modules: Vec<Module<'a>>,
+  indices: HashMap<&'a str, usize>,
+}
+
+impl<'a> Processor<'a> {
+  fn reset(&mut self) {
+    for module in self.modules.iter_mut() {
+      match module {
+        Module::FlipFlowModule(module) => {
+          module.enabled = false;
+        },
+        Module::ConjunctionModule(module) => {
+          for entry in module.remembered_signals.iter_mut() {
+            *entry.1 = false;
+          }
+        },
+        _ => {},
+      }
+    }
+  }
+}
+
+fn index_modules<'a>(modules: &[Module<'a>]) -> HashMap<&'a str, usize> {
+  let mut indices = HashMap::new();
+
+  for (index, module) in modules.iter().enumerate() {
+    match module {
+      Module::BroadcasterModule(module) => {
+        indices.insert(module.name, index);
+      },
+      Module::FlipFlowModule(module) => {
+        indices.insert(module.name, index);
+      },
+      Module::ConjunctionModule(module) => {
+        indices.insert(module.name, index);
+      },
+    }
+  }
+
+  indices
+}
+
+fn connect_conjunction_modules<'a>(mut modules: Vec<Module<'a>>, indices: &HashMap<&str, usize>) -> Vec<Module<'a>> {
+  let mut mappings = vec![];
+
+  for module in modules.iter() {
+    match module {
+      Module::BroadcasterModule(module) => {
+        for output in module.outputs.iter() {
+          if let Some(&output_index) = indices.get(output) {
+            mappings.push((module.name, output_index));
+          }
+        }
+      },
+      Module::FlipFlowModule(module) => {
+        for output in module.outputs.iter() {
+          if let Some(&output_index) = indices.get(output) {
+            mappings.push((module.name, output_index));
+          }
+        }
+      },
+      Module::ConjunctionModule(module) => {
+        for output in module.outputs.iter() {
+          if let Some(&output_index) = indices.get(output) {
+            mappings.push((module.name, output_index));
+          }
+        }
+      },
+    }
+  }
+
+  for (input_name,  output_index) in mappings {
+    if let Module::ConjunctionModule(module) = &mut modules[output_index] {
+      module.remembered_signals.insert(input_name, false);
+    }
+  }
+
+  modules
+}
+
+fn parse_modules<'a>(contents: &'a str) -> Result<Processor<'a>, Error> {
+  let modules = contents
+    .lines()
+    .enumerate()
+    .filter(|(_, line)| !line.trim().is_empty())
+    .map(|(index, line)| parse_module(index + 1, line))
+    .collect::<Result<Vec<Module>, Error>>()?;
+
+  let indices = index_modules(&modules);
+
+  let modules = connect_conjunction_modules(modules, &indices);
+
+  Ok(Processor{
+    modules,
+    indices,
+  })
+}
+
+trait ProcessSignal<'a> {
+  fn process(&mut self, signal: (&'a str, &'a str, bool)) -> Vec<(&'a str, &'a str, bool)>;
+}
+
+impl<'a> ProcessSignal<'a> for BroadcasterModule<'a> {
+  fn process(&mut self, (_, _, is_high): (&'a str, &'a str, bool)) -> Vec<(&'a str, &'a str, bool)> {
+    self.outputs
+      .iter()
+      .map(|&output_name| (self.name, output_name, is_high))
+      .collect()
+  }
+}
+
+impl<'a> ProcessSignal<'a> for FlipFlowModule<'a> {
+  fn process(&mut self, (_, _, is_high): (&'a str, &'a str, bool)) -> Vec<(&'a str, &'a str, bool)> {
+    if is_high {
+      return vec![];
+    }
+
+    self.enabled = !self.enabled;
+
+    self.outputs
+      .iter()
+      .map(|&output_name| (self.name, output_name, self.enabled))
+      .collect()
+  }
+}
+
+impl<'a> ProcessSignal<'a> for ConjunctionModule<'a> {
+  fn process(&mut self, (source_name, _, is_high): (&'a str, &'a str, bool)) -> Vec<(&'a str, &'a str, bool)> {
+    self.remembered_signals.insert(source_name, is_high);
+
+    let has_only_high = self.remembered_signals
+      .iter()
+      .all(|(_, &is_high)| is_high);
+
+    self.outputs
+      .iter()
+      .map(|&output_name| (self.name, output_name, !has_only_high))
+      .collect()
+  }
+}
+
+fn process_signal<'a>(processor: &mut Processor<'a>, initial_signal: (&'a str, &'a str, bool)) -> Result<(usize, usize), Error> {
+  let mut signals = VecDeque::new();
+  signals.push_back(initial_signal);
+
+  let mut high_signal_count = 0;
+  let mut low_signal_count = 0;
+  while let Some(signal) = signals.pop_front() {
+    let (_, target_name, is_high) = signal;
+
+    if is_high {
+      high_signal_count += 1;
+    } else {
+      low_signal_count += 1;
+    }
+
+    if high_signal_count + low_signal_count > PULSE_LIMIT {
+      return Err(Error::unsolvable(format!("a button press sends more than {PULSE_LIMIT} pulses")));
+    }
+
+    if let Some(&module_index) = processor.indices.get(target_name) {
+      let next_signals = match &mut processor.modules[module_index] {
+        Module::BroadcasterModule(module) => module.process(signal),
+        Module::FlipFlowModule(module) => module.process(signal),
+        Module::ConjunctionModule(module) => module.process(signal),
+      };
+
+      for next_signal in next_signals {
+        signals.push_back(next_signal);
+      }
+    }
+  }
+
+  Ok((high_signal_count, low_signal_count))
+}
+
+fn build_inverse_module_dependencies<'a>(processor: &'a Processor) -> HashMap<&'a str, Vec<&'a str>> {
+  let mut inverse_dependencies = HashMap::new();
+
+  let modules = &processor.modules;
+
+  for module in modules.iter() {
+    match module {
+      Module::BroadcasterModule(module) => {
+        for &output in module.outputs.iter() {
+          let dependencies = inverse_dependencies.entry(output).or_insert_with(Vec::new);
+          dependencies.push(module.name);
+        }
+      },
+      Module::FlipFlowModule(module) => {
+        for output in module.outputs.iter() {
+          let dependencies = inverse_dependencies.entry(output).or_insert_with(Vec::new);
+          dependencies.push(module.name);
+        }
+      },
+      Module::ConjunctionModule(module) => {
+        for output in module.outputs.iter() {
+          let dependencies = inverse_dependencies.entry(output).or_insert_with(Vec::new);
+          dependencies.push(module.name);
+        }
+      },
+    }
+  }
+
+  inverse_dependencies
+}
+
+/// Modules from which `start` can be reached, walking back from `start` without passing `end`.
+fn find_active_modules<'a>(inverse_dependencies: &HashMap<&'a str, Vec<&'a str>>, start: &'a str, end: &'a str) -> HashSet<&'a str> {
+  let dependencies = |&module_name: &&'a str| {
+    if module_name == end {
+      return Vec::new();
+    }
+
+    inverse_dependencies.get(module_name).cloned().unwrap_or_default()
+  };
+
+  let mut set = bfs_distances(&dependencies, start, usize::MAX)
+    .into_keys()
+    .collect::<HashSet<&str>>();
+
+  set.insert(end);
+
+  set
+}
+
+/// Pushes the button once within the subsection and reports whether `expected_signal` was sent,
+/// or `None` if the press sends more than [`PULSE_LIMIT`] pulses.
+fn press_subsection<'a>(processor: &mut Processor<'a>, active_modules: &HashSet<&str>, initial_signals: &Vec<(&'a str, &'a str, bool)>, expected_signal: (&str, bool)) -> Option<bool> {
+  let mut signals = VecDeque::new();
+  for &initial_signal in initial_signals {
+    signals.push_back(initial_signal);
+  }
+
+  let mut is_expected_signal_sent = false;
+  let mut signal_count = 0;
+
+  while let Some(signal) = signals.pop_front() {
+    let (_, target_name, is_high) = signal;
+
+    signal_count += 1;
+    if signal_count > PULSE_LIMIT {
+      return None;
+    }
+
+    if target_name == expected_signal.0 && is_high == expected_signal.1 {
+      is_expected_signal_sent = true;
+    }
+
+    if let Some(&module_index) = processor.indices.get(target_name) {
+      let next_signals = match &mut processor.modules[module_index] {
+        Module::BroadcasterModule(module) => module.process(signal),
+        Module::FlipFlowModule(module) => module.process(signal),
+        Module::ConjunctionModule(module) => module.process(signal),
+      };
+
+      for next_signal in next_signals {
+        if active_modules.contains(next_signal.1) {
+          signals.push_back(next_signal);
+        }
+      }
+    }
+  }
+
+  Some(is_expected_signal_sent)
+}
+
+fn count_impulses_for_subsection<'a>(processor: &mut Processor<'a>, active_modules: HashSet<&str>, initial_signals: Vec<(&'a str, &'a str, bool)>, expected_signal: (&str, bool)) -> Result<usize, Error> {
+  processor.reset();
+
+  for counter in 1..=PRESS_LIMIT {
+    match press_subsection(processor, &active_modules, &initial_signals, expected_signal) {
+      Some(true) => return Ok(counter),
+      Some(false) => {},
+      None => return Err(Error::unsolvable(format!("a button press sends more than {PULSE_LIMIT} pulses"))),
+    }
+  }
+
+  Err(Error::unsolvable(format!("\"{}\" gets no pulse within {PRESS_LIMIT} presses", expected_signal.0)))
+}
+
+/// Finds the cycle of the subsection's state under repeated button presses, along with the presses
+/// during which `expected_signal` is sent. A press that never settles ends the sequence in `None`.
+fn find_subsection_hits<'a>(processor: &Processor<'a>, active_modules: HashSet<&str>, initial_signals: Vec<(&'a str, &'a str, bool)>, expected_signal: (&str, bool)) -> Result<(Cycle, Vec<usize>), Error> {
+  let mut processor = processor.clone();
+  processor.reset();
+
+  let press = |state: &Option<(Processor<'a>, bool)>| {
+    let mut processor = match state {
+      Some((processor, _)) => processor.clone(),
+      None => return None,
+    };
+
+    press_subsection(&mut processor, &active_modules, &initial_signals, expected_signal)
+      .map(|is_expected_signal_sent| (processor, is_expected_signal_sent))
+  };
+
+  let start = Some((processor, false));
+  let cycle = match brent_within(&start, &press, PRESS_LIMIT) {
+    Some(cycle) => cycle,
+    None => return Err(Error::unsolvable(format!("the inputs of \"{}\" take over {PRESS_LIMIT} presses to repeat", expected_signal.0))),
+  };
+
+  let mut hits = vec![];
+  let mut state = start;
+  for presses in 0..(cycle.prefix + cycle.length) {
+    match &state {
+      Some((_, true)) => hits.push(presses),
+      Some((_, false)) => {},
+      None => return Err(Error::unsolvable(format!("a button press sends more than {PULSE_LIMIT} pulses"))),
+    }
+
+    state = press(&state);
+  }
+
+  Ok((cycle, hits))
+}
+
+/// Presses needed before `to` receives a low pulse, found by splitting the network at the first
+/// conjunction with several inputs and combining the cycles of each input's subsection. Assumes
+/// those inputs only stay high within the press that raised them, as with the puzzle's
+/// self-resetting counters; an input left high across presses can make the answer too large.
+fn find_minimum_impluses(processor: &Processor, from: &str, to: &str) -> Result<usize, Error> {
+  let inverse_dependencies = build_inverse_module_dependencies(processor);
+
+  let mut candidate = to;
+  let mut expected_signal = false;
+
+  for _ in 0..=processor.modules.len() {
+    let dependencies = match inverse_dependencies.get(candidate) {
+      Some(dependencies) => dependencies,
+      None => return Err(Error::unsolvable(format!("no module sends to \"{candidate}\""))),
+    };
+
+    if dependencies.is_empty() {
+      return Err(Error::unsolvable(format!("no module sends to \"{candidate}\"")));
+    } else if dependencies.len() == 1 {
+      candidate = dependencies[0];
+
+      let module_index = match processor.indices.get(candidate) {
+        Some(&module_index) => module_index,
+        None => return Err(Error::unsolvable(format!("no module named \"{candidate}\""))),
+      };
+
+      expected_signal = match &processor.modules[module_index] {
+        Module::BroadcasterModule(_) => expected_signal,
+        Module::FlipFlowModule(_) => false,
+        Module::ConjunctionModule(_) => true,
+      };
+    } else {
+      let subsections = dependencies
+        .iter()
+        .map(|&dependency| {
+          let mut active_modules = find_active_modules(&inverse_dependencies, dependency, from);
+          active_modules.insert(candidate);
+
+          find_subsection_hits(processor, active_modules, vec![("button", from, false)], (candidate, expected_signal))
+        })
+        .collect::<Result<Vec<(Cycle, Vec<usize>)>, Error>>()?;
+
+      let required_dependency_impulses = match first_common_hit(&subsections) {
+        Some(impulses) => impulses,
+        None => return Err(Error::unsolvable(format!("inputs of \"{candidate}\" never line up"))),
+      };
+
+      let head_initial_impulses = dependencies
+        .iter()
+        .map(|&dependency| (dependency, candidate, expected_signal))
+        .collect::<Vec<(&str, &str, bool)>>();
+
+      let head_active_modules = find_active_modules(&inverse_dependencies, to, candidate);
+
+      let head_impulses = count_impulses_for_subsection(&mut processor.clone(), head_active_modules, head_initial_impulses, (to, false))?;
+
+      return match required_dependency_impulses.checked_mul(head_impulses) {
+        Some(impulses) => Ok(impulses),
+        None => Err(Error::unsolvable("too many presses to count")),
+      };
+    }
+  }
+
+  Err(Error::unsolvable(format!("no conjunction with several inputs leads to \"{to}\"")))
+}
+
+/// Two to four binary counters of up to `size` bits, shaped like the puzzle's. Each is a chain of
+/// flip-flops whose set bits in an odd limit feed a conjunction, which pulses the other bits and
+/// the first one to reset the counter on reaching the limit, and an inverter into a shared
+/// conjunction before `rx`.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  let mut lines = vec![];
+  let mut starts = vec![];
+
+  for counter in 0..rng.range(2..5) {
+    let bits = (0..rng.range(2..size.max(2) as i64 + 1)).map(|bit| format!("f{counter}{bit}")).collect::<Vec<String>>();
+    let limit = rng.range(1 << (bits.len() - 1)..1 << bits.len()) | 1;
+    let conjunction = format!("c{counter}");
+
+    let mut resets = vec![format!("i{counter}")];
+
+    for (index, bit) in bits.iter().enumerate() {
+      let mut outputs = bits.get(index + 1).into_iter().cloned().collect::<Vec<String>>();
+
+      if limit & (1 << index) != 0 {
+        outputs.push(conjunction.clone());
+      }
+      if index == 0 || limit & (1 << index) == 0 {
+        resets.push(bit.clone());
+      }
+
+      rng.shuffle(&mut outputs);
+      lines.push(format!("%{bit} -> {}", outputs.join(", ")));
+    }
+
+    rng.shuffle(&mut resets);
+    lines.push(format!("&{conjunction} -> {}", resets.join(", ")));
+    lines.push(format!("&i{counter} -> hub"));
+
+    starts.push(bits[0].clone());
+  }
+
+  lines.push(format!("broadcaster -> {}", starts.join(", ")));
+  lines.push(String::from("&hub -> rx"));
+  rng.shuffle(&mut lines);
+
+  lines.join("\n")
+}
+
+pub struct Config {
+  /// How many times part 1 presses the button.
+  pub presses: usize,
+  /// The module part 2 waits on for a low pulse.
+  pub target: String,
+}
+
+impl Default for Config {
+  fn default() -> Self {
+    Config{ presses: 1000, target: String::from("rx") }
+  }
+}
+
+impl config::Config for Config {
+  const FLAGS: &'static [(&'static str, &'static str)] = &[("presses", "n"), ("target", "module")];
+
+  fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+    match name {
+      "presses" => parse_value(name, value).map(|presses| self.presses = presses),
+      "target" => {
+        self.target = value.to_string();
+        Ok(())
+      },
+      _ => Ok(()),
+    }
+  }
+}
+
+fn too_many_pulses() -> Error {
+  Error::unsolvable("too many pulses to multiply")
+}
+
+fn pulse_product(high_signal_count: usize, low_signal_count: usize) -> Result<Answer, Error> {
+  match high_signal_count.checked_mul(low_signal_count) {
+    Some(product) => Ok(product.into()),
+    None => Err(too_many_pulses()),
+  }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+  type Input<'a> = Processor<'a>;
+
+  type Config = Config;
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    parse_modules(contents)
+  }
+
+  fn part1(processor: &Self::Input<'_>, config: &Self::Config) -> Result<Answer, Error> {
+    let mut processor = processor.clone();
+
+    let signal_counts = (0..config.presses)
+      .try_fold((0usize, 0usize), |acc, _| {
+        let (high_signal_count, low_signal_count) = match process_signal(&mut processor, ("button", "broadcaster", false)) {
+          Ok(signal_counts) => signal_counts,
+          Err(error) => return Err(error),
+        };
+
+        match (acc.0.checked_add(high_signal_count), acc.1.checked_add(low_signal_count)) {
+          (Some(high_signal_count), Some(low_signal_count)) => Ok((high_signal_count, low_signal_count)),
+          _ => Err(too_many_pulses()),
+        }
+      });
+
+    match signal_counts {
+      Ok((high_signal_count, low_signal_count)) => pulse_product(high_signal_count, low_signal_count),
+      Err(error) => Err(error),
+    }
+  }
+
+  fn part2(processor: &Self::Input<'_>, config: &Self::Config) -> Result<Answer, Error> {
+    find_minimum_impluses(processor, "broadcaster", &config.target)
+      .map(|impulse_count| impulse_count.into())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{differential::assert_agree, fuzz::assert_no_panic, generate::assert_solvable};
+
+  use super::*;
+
+  /// Beyond this many presses both solvers count as finding no answer.
+  const PRESS_LIMIT: usize = 5000;
+
+  /// Presses the button until `to` receives a low pulse.
+  fn press_until_low_pulse(processor: &Processor, to: &str) -> Option<usize> {
+    let mut processor = processor.clone();
+
+    for presses in 1..PRESS_LIMIT {
+      let mut signals = VecDeque::from([("button", "broadcaster", false)]);
+
+      while let Some(signal) = signals.pop_front() {
+        let (_, target_name, is_high) = signal;
+
+        if target_name == to && !is_high {
+          return Some(presses);
+        }
+
+        if let Some(&module_index) = processor.indices.get(target_name) {
+          signals.extend(match &mut processor.modules[module_index] {
+            Module::BroadcasterModule(module) => module.process(signal),
+            Module::FlipFlowModule(module) => module.process(signal),
+            Module::ConjunctionModule(module) => module.process(signal),
+          });
+        }
+      }
+    }
+
+    None
+  }
+
+  #[test]
+  fn subsections_agree_with_pressing_the_button() {
+    let fast = |contents: &str| find_minimum_impluses(&Day20::parse(contents).unwrap(), "broadcaster", "rx").ok().filter(|&presses| presses < PRESS_LIMIT);
+
+    assert_agree(0..300, |rng| generate(rng, 3), fast, |contents| press_until_low_pulse(&Day20::parse(contents).unwrap(), "rx"));
+  }
+
+  const EXAMPLE_1: &str = "\
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a
+";
+
+  const EXAMPLE_2: &str = "\
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output
+";
+
+  /// Two counters that first fire on presses 3 and 5, which are not their cycle lengths.
+  const OFFSET_COUNTERS: &str = "\
+broadcaster -> a1, b1
+%a1 -> a2, ca
+%a2 -> ca
+&ca -> ia
+&ia -> hub
+%b1 -> b2, cb
+%b2 -> b3
+%b3 -> cb
+&cb -> ib
+&ib -> hub
+&hub -> rx
+";
+
+  #[test]
+  fn part1_example() {
+    let input = Day20::parse(EXAMPLE_1).unwrap();
+
+    assert_eq!(Day20::part1(&input, &Config::default()), Ok(Answer::Unsigned(32000000)));
+  }
+
+  #[test]
+  fn part1_example_with_conjunction() {
+    let input = Day20::parse(EXAMPLE_2).unwrap();
+
+    assert_eq!(Day20::part1(&input, &Config::default()), Ok(Answer::Unsigned(11687500)));
+  }
+
+  #[test]
+  fn part1_single_press() {
+    let input = Day20::parse(EXAMPLE_1).unwrap();
+
+    assert_eq!(Day20::part1(&input, &Config{ presses: 1, ..Config::default() }), Ok(Answer::Unsigned(32)));
+  }
+
+  #[test]
+  fn pulse_counts_too_large_to_multiply_are_unsolvable() {
+    assert_eq!(pulse_product(4, 8), Ok(Answer::Unsigned(32)));
+    assert_eq!(pulse_product(usize::MAX / 2, 3), Err(too_many_pulses()));
+  }
+
+  #[test]
+  fn part2_counters_not_starting_at_zero() {
+    let input = Day20::parse(OFFSET_COUNTERS).unwrap();
+
+    assert_eq!(Day20::part2(&input, &Config::default()), Ok(Answer::Unsigned(7)));
+  }
+
+  #[test]
+  fn part2_waits_on_the_configured_target() {
+    let contents = OFFSET_COUNTERS.replace("rx", "out");
+    let input = Day20::parse(&contents).unwrap();
+
+    assert_eq!(Day20::part2(&input, &Config{ target: String::from("out"), ..Config::default() }), Ok(Answer::Unsigned(7)));
+    assert!(Day20::part2(&input, &Config::default()).is_err());
+  }
+
+  #[test]
+  fn pulses_that_never_settle_are_unsolvable() {
+    let input = Day20::parse("broadcaster -> a\n&a -> a, rx").unwrap();
+
+    assert!(matches!(Day20::part1(&input, &Config::default()), Err(Error::Unsolvable(_))));
+    assert!(matches!(Day20::part2(&input, &Config::default()), Err(Error::Unsolvable(_))));
+  }
+
+  #[test]
+  fn malformed_modules_are_positioned() {
+    assert_eq!(Day20::parse("broadcaster -> a\n%a -> b,\n&b -> rx").err(), Some(Error::parse(2, 9, "expected an identifier")));
+    assert_eq!(Day20::parse("broadcaster -> a\n\n  &a => rx").err(), Some(Error::parse(3, 6, "expected \"->\"").spanning(1)));
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day20>(generate, 0..20, 6);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day20>(generate, 0..100, 6);
+  }
+}
@@ -0,0 +1,383 @@
+use aoc_common::{Answer, Error, Grid, Solution, config::{self, parse_value}, graph::bfs_distances, random::Rng};
+
+fn flood_fill(maze: &Grid<char>, start_position: (usize, usize), start_step: usize, max_steps: usize) -> Grid<usize> {
+  let plots = |&position: &(usize, usize)| maze.neighbors4(position).filter(|&neighbor| maze[neighbor] == '.');
+
+  let mut steps_map = maze.map(|_| usize::MAX);
+
+  for (position, distance) in bfs_distances(&plots, start_position, max_steps.saturating_sub(start_step)) {
+    steps_map[position] = start_step + distance;
+  }
+
+  steps_map
+}
+
+fn count_even_steps(steps_map: &Grid<usize>) -> usize {
+  steps_map
+    .positions()
+    .filter(|&position| steps_map[position] != usize::MAX && steps_map[position].is_multiple_of(2))
+    .count()
+}
+
+fn count_odd_steps(steps_map: &Grid<usize>) -> usize {
+  steps_map
+    .positions()
+    .filter(|&position| steps_map[position] != usize::MAX && steps_map[position] % 2 == 1)
+    .count()
+}
+
+/// Whether [`count_infinite_garden_plots`] handles `steps` on the garden: a square garden of odd
+/// size with the start in the middle and clear lanes along the border and through the start, and
+/// an odd step count that ends exactly on the border of a tile an even number of tiles out.
+fn tiles_evenly(garden: &Garden, steps: usize) -> bool {
+  let maze = &garden.maze;
+  let size = maze.rows();
+  let middle = size / 2;
+
+  if size != maze.cols() || size.is_multiple_of(2) || garden.start_position != (middle, middle) {
+    return false;
+  }
+
+  let lanes_are_clear = [0, middle, size - 1].into_iter().all(|line| {
+    (0..size).all(|index| maze[(line, index)] == '.' && maze[(index, line)] == '.')
+  });
+
+  lanes_are_clear
+    && steps % 2 == 1
+    && steps >= middle + 2 * size
+    && (steps - middle).is_multiple_of(size)
+    && ((steps - middle) / size).is_multiple_of(2)
+}
+
+/// Plots reachable in exactly `steps` steps on the infinitely tiled garden, for a garden and step
+/// count for which [`tiles_evenly`] holds.
+fn count_infinite_garden_plots(garden: &Garden, steps: usize) -> usize {
+  let maze = &garden.maze;
+  let start_position = garden.start_position;
+
+  let last_row = maze.rows() - 1;
+  let last_col = maze.cols() - 1;
+
+  let steps_map = flood_fill(maze, start_position, 0, usize::MAX);
+
+  let steps_map_count = count_odd_steps(&steps_map);
+
+  let start_position_s = (0, start_position.1);
+  let start_position_n = (last_row, start_position.1);
+  let start_position_e = (start_position.0, 0);
+  let start_position_w = (start_position.0, last_col);
+
+  let start_position_se = (0, 0);
+  let start_position_ne = (last_row, 0);
+  let start_position_sw = (0, last_col);
+  let start_position_nw = (last_row, last_col);
+
+  let dim = maze.rows();
+
+  let steps_map_s_odd = flood_fill(maze, start_position_s, 1, usize::MAX);
+  let steps_map_s_even = flood_fill(maze, start_position_s, 0, usize::MAX);
+  let steps_map_s_odd_count = count_odd_steps(&steps_map_s_odd);
+  let steps_map_s_even_count = count_odd_steps(&steps_map_s_even);
+
+  let steps_map_s_edge = flood_fill(maze, start_position_s, 1, dim);
+  let steps_map_s_edge_count = count_odd_steps(&steps_map_s_edge);
+
+  let steps_map_n_odd = flood_fill(maze, start_position_n, 1, usize::MAX);
+  let steps_map_n_even = flood_fill(maze, start_position_n, 0, usize::MAX);
+  let steps_map_n_odd_count = count_odd_steps(&steps_map_n_odd);
+  let steps_map_n_even_count = count_odd_steps(&steps_map_n_even);
+
+  let steps_map_n_edge = flood_fill(maze, start_position_n, 1, dim);
+  let steps_map_n_edge_count = count_odd_steps(&steps_map_n_edge);
+
+  let steps_map_e_odd = flood_fill(maze, start_position_e, 1, usize::MAX);
+  let steps_map_e_even = flood_fill(maze, start_position_e, 0, usize::MAX);
+  let steps_map_e_odd_count = count_odd_steps(&steps_map_e_odd);
+  let steps_map_e_even_count = count_odd_steps(&steps_map_e_even);
+
+  let steps_map_e_edge = flood_fill(maze, start_position_e, 1, dim);
+  let steps_map_e_edge_count = count_odd_steps(&steps_map_e_edge);
+
+  let steps_map_w_odd = flood_fill(maze, start_position_w, 1, usize::MAX);
+  let steps_map_w_even = flood_fill(maze, start_position_w, 0, usize::MAX);
+  let steps_map_w_odd_count = count_odd_steps(&steps_map_w_odd);
+  let steps_map_w_even_count = count_odd_steps(&steps_map_w_even);
+
+  let steps_map_w_edge = flood_fill(maze, start_position_w, 1, dim);
+  let steps_map_w_edge_count = count_odd_steps(&steps_map_w_edge);
+
+  let steps_map_se_odd = flood_fill(maze, start_position_se, 1, usize::MAX);
+  let steps_map_se_even = flood_fill(maze, start_position_se, 0, usize::MAX);
+  let steps_map_se_odd_count = count_odd_steps(&steps_map_se_odd);
+  let steps_map_se_even_count = count_odd_steps(&steps_map_se_even);
+
+  let steps_map_se_lesser_edge = flood_fill(maze, start_position_se, 1, dim / 2);
+  let steps_map_se_lesser_edge_count = count_odd_steps(&steps_map_se_lesser_edge);
+
+  let steps_map_se_greater_edge = flood_fill(maze, start_position_se, 0, dim + (dim / 2) - 1);
+  let steps_map_se_greater_edge_count = count_odd_steps(&steps_map_se_greater_edge);
+
+  let steps_map_ne_odd = flood_fill(maze, start_position_ne, 1, usize::MAX);
+  let steps_map_ne_even = flood_fill(maze, start_position_ne, 0, usize::MAX);
+  let steps_map_ne_odd_count = count_odd_steps(&steps_map_ne_odd);
+  let steps_map_ne_even_count = count_odd_steps(&steps_map_ne_even);
+
+  let steps_map_ne_lesser_edge = flood_fill(maze, start_position_ne, 1, dim / 2);
+  let steps_map_ne_lesser_edge_count = count_odd_steps(&steps_map_ne_lesser_edge);
+
+  let steps_map_ne_greater_edge = flood_fill(maze, start_position_ne, 0, dim + (dim / 2) - 1);
+  let steps_map_ne_greater_edge_count = count_odd_steps(&steps_map_ne_greater_edge);
+
+  let steps_map_sw_odd = flood_fill(maze, start_position_sw, 1, usize::MAX);
+  let steps_map_sw_even = flood_fill(maze, start_position_sw, 0, usize::MAX);
+  let steps_map_sw_odd_count = count_odd_steps(&steps_map_sw_odd);
+  let steps_map_sw_even_count = count_odd_steps(&steps_map_sw_even);
+
+  let steps_map_sw_lesser_edge = flood_fill(maze, start_position_sw, 1, dim / 2);
+  let steps_map_sw_lesser_edge_count = count_odd_steps(&steps_map_sw_lesser_edge);
+
+  let steps_map_sw_greater_edge = flood_fill(maze, start_position_sw, 0, dim + (dim / 2) - 1);
+  let steps_map_sw_greater_edge_count = count_odd_steps(&steps_map_sw_greater_edge);
+
+  let steps_map_nw_odd = flood_fill(maze, start_position_nw, 1, usize::MAX);
+  let steps_map_nw_even = flood_fill(maze, start_position_nw, 0, usize::MAX);
+  let steps_map_nw_odd_count = count_odd_steps(&steps_map_nw_odd);
+  let steps_map_nw_even_count = count_odd_steps(&steps_map_nw_even);
+
+  let steps_map_nw_lesser_edge = flood_fill(maze, start_position_nw, 1, dim / 2);
+  let steps_map_nw_lesser_edge_count = count_odd_steps(&steps_map_nw_lesser_edge);
+
+  let steps_map_nw_greater_edge = flood_fill(maze, start_position_nw, 0, dim + (dim / 2) - 1);
+  let steps_map_nw_greater_edge_count = count_odd_steps(&steps_map_nw_greater_edge);
+
+  let axis_repeats = (steps - dim / 2) / dim;
+  let diagonal_even_repeats = axis_repeats * (axis_repeats - 2) / 4;
+  let diagonal_odd_repeats = (axis_repeats - 2) * (axis_repeats - 2) / 4;
+
+  steps_map_count +
+
+  ((axis_repeats - 1) / 2) * (steps_map_s_odd_count + steps_map_n_odd_count + steps_map_e_odd_count + steps_map_w_odd_count)+
+  (axis_repeats / 2) * (steps_map_s_even_count + steps_map_n_even_count + steps_map_e_even_count + steps_map_w_even_count) +
+
+  diagonal_even_repeats * (steps_map_se_odd_count + steps_map_ne_odd_count + steps_map_sw_odd_count + steps_map_nw_odd_count) +
+  diagonal_odd_repeats * (steps_map_se_even_count + steps_map_ne_even_count + steps_map_sw_even_count + steps_map_nw_even_count) +
+
+  (steps_map_s_edge_count + steps_map_n_edge_count + steps_map_e_edge_count + steps_map_w_edge_count) +
+  axis_repeats * (steps_map_se_lesser_edge_count + steps_map_ne_lesser_edge_count + steps_map_sw_lesser_edge_count + steps_map_nw_lesser_edge_count) +
+  (axis_repeats - 1) * (steps_map_se_greater_edge_count + steps_map_ne_greater_edge_count + steps_map_sw_greater_edge_count + steps_map_nw_greater_edge_count)
+}
+
+/// Step counts beyond this make [`count_tiled_garden_plots`] too slow to be worth waiting for.
+const MAX_SEARCH_STEPS: usize = 1000;
+
+/// Plots reachable in exactly `steps` steps on the infinitely tiled garden by searching every
+/// plot within reach, for step counts that [`count_infinite_garden_plots`] cannot handle.
+fn count_tiled_garden_plots(garden: &Garden, steps: usize) -> usize {
+  let plots = |&(row, col): &(isize, isize)| {
+    [(row - 1, col), (row + 1, col), (row, col - 1), (row, col + 1)]
+      .into_iter()
+      .filter(|&(row, col)| *garden.maze.get_wrapped(row, col) == '.')
+  };
+
+  let start = (garden.start_position.0 as isize, garden.start_position.1 as isize);
+
+  bfs_distances(&plots, start, steps)
+    .into_values()
+    .filter(|distance| distance % 2 == steps % 2)
+    .count()
+}
+
+pub struct Garden {
+  maze: Grid<char>,
+  start_position: (usize, usize),
+}
+
+/// A garden of at least `size` plots across, rounded up to the next size that is three more than a
+/// multiple of four, with the clear lanes [`count_infinite_garden_plots`] needs. Of those sizes only
+/// the puzzle's own 131 suits its step count. Rocks cover about a quarter of the tiles off the
+/// clear lanes.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  let size = (size.max(7) - 3).next_multiple_of(4) + 3;
+  let middle = size / 2;
+
+  let mut contents = String::new();
+  for row in 0..size {
+    for col in 0..size {
+      let is_lane = row == 0 || col == 0 || row == size - 1 || col == size - 1 || row == middle || col == middle;
+
+      contents.push(match (row, col) {
+        _ if row == middle && col == middle => 'S',
+        _ if !is_lane && rng.chance(1, 4) => '#',
+        _ => '.',
+      });
+    }
+    contents.push('\n');
+  }
+
+  contents
+}
+
+pub struct Config {
+  /// Steps taken within the garden in part 1.
+  pub steps: usize,
+  /// Steps taken across the tiled garden in part 2.
+  pub tiled_steps: usize,
+}
+
+impl Default for Config {
+  fn default() -> Self {
+    Config{ steps: 64, tiled_steps: 26501365 }
+  }
+}
+
+impl config::Config for Config {
+  const FLAGS: &'static [(&'static str, &'static str)] = &[("steps", "n"), ("tiled-steps", "n")];
+
+  fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+    match name {
+      "steps" => parse_value(name, value).map(|steps| self.steps = steps),
+      "tiled-steps" => parse_value(name, value).map(|steps| self.tiled_steps = steps),
+      _ => Ok(()),
+    }
+  }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+  type Input<'a> = Garden;
+
+  type Config = Config;
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    let mut maze = Grid::parse(contents)?;
+
+    let start_position = match maze.find(|&tile| tile == 'S') {
+      Some(start_position) => start_position,
+      None => return Err(Error::unsolvable("no starting position")),
+    };
+
+    maze[start_position] = '.';
+
+    Ok(Garden{ maze, start_position })
+  }
+
+  fn part1(garden: &Self::Input<'_>, config: &Self::Config) -> Result<Answer, Error> {
+    let steps_map = flood_fill(&garden.maze, garden.start_position, 0, config.steps);
+
+    if config.steps.is_multiple_of(2) {
+      Ok(count_even_steps(&steps_map).into())
+    } else {
+      Ok(count_odd_steps(&steps_map).into())
+    }
+  }
+
+  fn part2(garden: &Self::Input<'_>, config: &Self::Config) -> Result<Answer, Error> {
+    if tiles_evenly(garden, config.tiled_steps) {
+      return Ok(count_infinite_garden_plots(garden, config.tiled_steps).into());
+    }
+
+    if config.tiled_steps > MAX_SEARCH_STEPS {
+      return Err(Error::unsolvable(format!("the tile formula does not apply to {} steps and they are too many to search", config.tiled_steps)));
+    }
+
+    Ok(count_tiled_garden_plots(garden, config.tiled_steps).into())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{differential::assert_agree, fuzz::assert_no_panic, generate::assert_solvable};
+
+  use super::*;
+
+  #[test]
+  fn tiled_gardens_agree_with_search() {
+    let generate_small = |rng: &mut Rng| {
+      let size = *rng.choose(&[7, 11]);
+      let contents = generate(rng, size);
+
+      match rng.below(3) {
+        0 => contents,
+        1 => contents.lines().skip(1).map(|line| format!("{line}\n")).collect(),
+        _ => contents.replacen("S.", ".S", 1),
+      }
+    };
+
+    for repeats in [2, 4] {
+      for offset in [0, 2, 4] {
+        let config = |garden: &Garden| Config{ tiled_steps: garden.maze.rows() / 2 + repeats * garden.maze.rows() + offset, ..Config::default() };
+
+        let fast = |contents: &str| {
+          let garden = Day21::parse(contents).unwrap();
+
+          Day21::part2(&garden, &config(&garden))
+        };
+        let naive = |contents: &str| {
+          let garden = Day21::parse(contents).unwrap();
+
+          Ok(count_tiled_garden_plots(&garden, config(&garden).tiled_steps).into())
+        };
+
+        assert_agree(0..40, generate_small, fast, naive);
+      }
+    }
+  }
+
+  const EXAMPLE: &str = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+";
+
+  #[test]
+  fn six_steps_example() {
+    let garden = Day21::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day21::part1(&garden, &Config{ steps: 6, ..Config::default() }), Ok(Answer::Unsigned(16)));
+    assert_eq!(Day21::part1(&garden, &Config{ steps: 3, ..Config::default() }), Ok(Answer::Unsigned(6)));
+  }
+
+  #[test]
+  fn tiled_garden_examples() {
+    let garden = Day21::parse(EXAMPLE).unwrap();
+    let tiled = |tiled_steps: usize| Day21::part2(&garden, &Config{ tiled_steps, ..Config::default() });
+
+    assert_eq!(tiled(6), Ok(Answer::Unsigned(16)));
+    assert_eq!(tiled(10), Ok(Answer::Unsigned(50)));
+    assert_eq!(tiled(50), Ok(Answer::Unsigned(1594)));
+    assert_eq!(tiled(100), Ok(Answer::Unsigned(6536)));
+    assert!(tiled(2 * MAX_SEARCH_STEPS).is_err());
+  }
+
+  #[test]
+  fn step_counts_off_the_tile_border_are_searched() {
+    let contents = generate(&mut Rng::new(1), 11);
+    let garden = Day21::parse(&contents).unwrap();
+
+    for tiled_steps in [27, 29, 31, 51] {
+      assert_eq!(Day21::part2(&garden, &Config{ tiled_steps, ..Config::default() }), Ok(count_tiled_garden_plots(&garden, tiled_steps).into()));
+    }
+
+    let off_centre = Day21::parse(&contents.replacen('S', ".", 1).replacen('.', "S", 1)).unwrap();
+    assert!(!tiles_evenly(&off_centre, 27));
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day21>(generate, 0..3, 131);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day21>(generate, 0..100, 11);
+  }
+}
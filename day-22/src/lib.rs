@@ -0,0 +1,317 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_common::{Answer, Error, Solution, extract::exactly, parser::{and_then, parse_line, rest, separated_pair, tag, take_while}, random::Rng};
+
+#[derive(Clone)]
+pub struct Brick {
+  start: [usize; 3],
+  end: [usize; 3],
+}
+
+/// The z-buffer covers every `(x, y)` below this, so larger footprints are refused.
+const MAX_FOOTPRINT: usize = 1000;
+
+fn parse_bricks(contents: &str) -> Result<Vec<Brick>, Error> {
+  let corners = separated_pair(
+    and_then(take_while(|char| char != '~'), exactly::<usize, 3>),
+    tag("~"),
+    and_then(rest, exactly::<usize, 3>),
+  );
+
+  contents
+    .lines()
+    .enumerate()
+    .filter(|(_, line)| !line.trim().is_empty())
+    .map(|(index, line)| {
+      let (mut start, mut end) = match parse_line(index + 1, line, &corners) {
+        Ok(corners) => corners,
+        Err(error) => return Err(error),
+      };
+
+      for i in 0..3 {
+        (start[i], end[i]) = (start[i].min(end[i]), start[i].max(end[i]));
+      }
+
+      if end[0] >= MAX_FOOTPRINT || end[1] >= MAX_FOOTPRINT {
+        return Err(Error::parse(index + 1, 0, format!("x and y must be below {MAX_FOOTPRINT}")));
+      }
+      if end[2] > u32::MAX as usize {
+        return Err(Error::parse(index + 1, 0, format!("z must be at most {}", u32::MAX)));
+      }
+
+      Ok(Brick{
+        start,
+        end,
+      })
+    })
+    .collect()
+}
+
+fn sort_bricks_by_z(mut bricks: Vec<Brick>) -> Vec<Brick> {
+  bricks.sort_by(|a, b| a.end[2].cmp(&b.end[2]));
+
+  bricks
+}
+
+fn find_z_buffer_extents(bricks: &[Brick]) -> (usize, usize, usize) {
+  let mut extents = (0, 0, 0);
+
+  for brick in bricks.iter() {
+    extents.0 = extents.0.max(brick.end[0] + 1);
+    extents.1 = extents.1.max(brick.end[1] + 1);
+    extents.2 = extents.2.max(brick.end[2] + 1);
+  }
+
+  extents
+}
+
+#[allow(clippy::needless_range_loop)]
+fn find_highest_z(z_buffer: &[Vec<(usize, usize)>], x: (usize, usize), y: (usize, usize)) -> usize {
+  let mut acc = 0;
+
+  for row in x.0..=x.1 {
+    for col in y.0..=y.1 {
+      acc = acc.max(z_buffer[row][col].0);
+    }
+  }
+
+  acc
+}
+
+#[allow(clippy::needless_range_loop)]
+fn apply_gravity(mut bricks: Vec<Brick>) -> (Vec<Brick>, HashSet<(usize, usize)>) {
+  let extents = find_z_buffer_extents(&bricks);
+
+  let mut z_buffer = (0..=extents.0)
+    .map(|_| vec![(0, usize::MAX); extents.1 + 1])
+    .collect::<Vec<Vec<(usize, usize)>>>();
+
+  let mut collissions = HashSet::new();
+
+  for index in 0..bricks.len() {
+    let x = (bricks[index].start[0], bricks[index].end[0]);
+    let y = (bricks[index].start[1], bricks[index].end[1]);
+
+    let z = find_highest_z(&z_buffer, x, y);
+
+    let diff: usize = bricks[index].end[2] - bricks[index].start[2];
+
+    bricks[index].start[2] = z + 1;
+    bricks[index].end[2] = bricks[index].start[2] + diff;
+
+    for row in x.0..=x.1 {
+      for col in y.0..=y.1 {
+        if z_buffer[row][col].0 == z && z_buffer[row][col].1 != usize::MAX {
+          collissions.insert((z_buffer[row][col].1, index));
+        }
+
+        z_buffer[row][col].0 = bricks[index].end[2];
+        z_buffer[row][col].1 = index;
+      }
+    }
+  }
+
+  (bricks, collissions)
+}
+
+fn collect_brick_collission_counts(bricks: &[Brick], collissions: &HashSet<(usize, usize)>) -> Vec<usize> {
+  let mut results = vec![0; bricks.len()];
+
+  for &(_, index_above) in collissions.iter() {
+    results[index_above] += 1;
+  }
+
+  results
+}
+
+fn group_by(bricks: &[Brick], collissions: &HashSet<(usize, usize)>, key_getter: fn(&(usize, usize)) -> usize, value_getter: fn(&(usize, usize)) -> usize) -> Vec<Vec<usize>> {
+  let mut results = (0..bricks.len())
+    .map(|_| vec![])
+    .collect::<Vec<Vec<usize>>>();
+
+  for entry in collissions.iter() {
+    results[key_getter(entry)].push(value_getter(entry));
+  }
+
+  results
+}
+
+fn count_nonessensial_bricks(bricks: Vec<Brick>) -> usize {
+  let bricks = sort_bricks_by_z(bricks);
+  let (bricks, collisions) = apply_gravity(bricks);
+
+  let brick_collission_counts = collect_brick_collission_counts(&bricks, &collisions);
+  let below_to_above_mapping = group_by(&bricks, &collisions, |&(index_below, _)| index_below, |&(_, index_above)| index_above);
+
+  let mut count = 0;
+
+  for indices_above in below_to_above_mapping.iter() {
+    if indices_above.iter().all(|&index_above| brick_collission_counts[index_above] > 1) {
+      count += 1;
+    }
+  }
+
+  count
+}
+
+fn count_fallen_bricks(index: usize, below_to_above_mapping: &[Vec<usize>], above_to_below_mapping: &[Vec<usize>], brick_collission_counts: &[usize]) -> usize {
+  let mut bricks_removed = vec![false; brick_collission_counts.len()];
+  bricks_removed[index] = true;
+
+  let mut queue = VecDeque::new();
+  queue.push_back(index);
+
+  while let Some(index) = queue.pop_front() {
+    for &index_above in below_to_above_mapping[index].iter() {
+      if above_to_below_mapping[index_above].iter().all(|&index_below| bricks_removed[index_below]) {
+        bricks_removed[index_above] = true;
+
+        queue.push_back(index_above);
+      }
+    }
+  }
+
+  bricks_removed
+    .iter()
+    .filter(|&value| *value)
+    .count() - 1
+}
+
+fn count_unstable_bricks(bricks: Vec<Brick>) -> usize {
+  let bricks = sort_bricks_by_z(bricks);
+  let (bricks, collisions) = apply_gravity(bricks);
+
+  let brick_collission_counts = collect_brick_collission_counts(&bricks, &collisions);
+  let below_to_above_mapping = group_by(&bricks, &collisions, |&(index_below, _)| index_below, |&(_, index_above)| index_above);
+  let above_to_below_mapping = group_by(&bricks, &collisions, |&(_, index_above)| index_above, |&(index_below, _)| index_below);
+
+  let mut count = 0;
+  for (index, indices_above) in below_to_above_mapping.iter().enumerate() {
+    if indices_above.iter().any(|&index_above| brick_collission_counts[index_above] <= 1) {
+      count += count_fallen_bricks(index, &below_to_above_mapping, &above_to_below_mapping, &brick_collission_counts);
+    }
+  }
+
+
+  count
+}
+
+/// A snapshot of `size` bricks of up to four cubes, floating over a ten by ten area without
+/// overlapping.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  let mut occupied = HashSet::new();
+  let mut lines = vec![];
+
+  while lines.len() < size {
+    let axis = rng.below(3);
+    let length = rng.range(1..5) as usize;
+
+    let mut start = [rng.below(10), rng.below(10), rng.range(1..(size * 2).max(2) as i64) as usize];
+    if axis < 2 {
+      start[axis] = start[axis].min(10 - length);
+    }
+
+    let mut end = start;
+    end[axis] = start[axis] + length - 1;
+
+    let cubes = (0..length)
+      .map(|offset| {
+        let mut cube = start;
+        cube[axis] += offset;
+        cube
+      })
+      .collect::<Vec<[usize; 3]>>();
+
+    if cubes.iter().any(|cube| occupied.contains(cube)) {
+      continue;
+    }
+
+    occupied.extend(cubes);
+    lines.push(format!("{},{},{}~{},{},{}\n", start[0], start[1], start[2], end[0], end[1], end[2]));
+  }
+
+  lines.concat()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+  type Input<'a> = Vec<Brick>;
+
+  type Config = ();
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    parse_bricks(contents)
+  }
+
+  fn part1(bricks: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    Ok(count_nonessensial_bricks(bricks.clone()).into())
+  }
+
+  fn part2(bricks: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    Ok(count_unstable_bricks(bricks.clone()).into())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{fuzz::assert_no_panic, generate::assert_solvable};
+
+  use super::*;
+
+  const EXAMPLE: &str = "\
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+";
+
+  #[test]
+  fn part1_example() {
+    let input = Day22::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day22::part1(&input, &()), Ok(Answer::Unsigned(5)));
+  }
+
+  #[test]
+  fn part2_example() {
+    let input = Day22::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day22::part2(&input, &()), Ok(Answer::Unsigned(7)));
+  }
+
+  #[test]
+  fn corners_may_come_in_either_order() {
+    let reversed = EXAMPLE
+      .lines()
+      .map(|line| line.split('~').rev().collect::<Vec<&str>>().join("~"))
+      .collect::<Vec<String>>()
+      .join("\n");
+    let input = Day22::parse(&reversed).unwrap();
+
+    assert_eq!(Day22::part1(&input, &()), Ok(Answer::Unsigned(5)));
+    assert_eq!(Day22::part2(&input, &()), Ok(Answer::Unsigned(7)));
+  }
+
+  #[test]
+  fn stray_characters_between_numbers_are_refused() {
+    let result = Day22::parse("1;0;1~1,2,1");
+    assert_eq!(result.err(), Some(Error::parse(1, 2, "expected whitespace or \",\" after a number").spanning(1)));
+
+    let result = Day22::parse("1,0,1~1 2 1 zz");
+    assert_eq!(result.err(), Some(Error::parse(1, 13, "expected a number").spanning(2)));
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day22>(generate, 0..20, 30);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day22>(generate, 0..100, 30);
+  }
+}
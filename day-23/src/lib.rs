@@ -0,0 +1,369 @@
+use std::collections::{HashSet, VecDeque, HashMap};
+
+use aoc_common::{Answer, Direction, Error, Grid, Solution, graph::dag_longest_paths, random::Rng};
+
+fn find_openning_column(row: &[char]) -> Option<usize> {
+  row.iter().position(|&c| c == '.')
+}
+
+fn next_coord(trials_map: &Grid<char>, coords: &(usize, usize), direction: Direction) -> Option<(Direction, (usize, usize))> {
+  trials_map
+    .offset(*coords, direction.delta())
+    .map(|coords| (direction, coords))
+}
+
+fn find_next_node(trials_map: &Grid<char>, coord: &(usize, usize), direction: Direction) -> Vec<(Direction, (usize, usize))> {
+  [direction.turn_left(), direction, direction.turn_right()]
+    .iter()
+    .filter_map(|&direction| next_coord(trials_map, coord, direction))
+    .collect()
+}
+
+#[allow(clippy::type_complexity)]
+fn build_graph(trials_map: &Grid<char>, entrance: (usize, usize)) -> HashMap<(usize, usize), HashSet<(usize, (usize, usize))>> {
+  let mut result = HashMap::new();
+  let mut explored = HashSet::new();
+
+  let mut queue = VecDeque::new();
+  for (next_direction, next_coord) in find_next_node(trials_map, &entrance, Direction::Down) {
+    if trials_map[next_coord] != '#' {
+      queue.push_back((entrance, next_direction, next_coord));
+    }
+  }
+
+  while let Some((prev_coord, mut direction, mut coord)) = queue.pop_front() {
+    let mut steps = 0;
+
+    loop {
+      steps += 1;
+
+      if steps > trials_map.rows() * trials_map.cols() {
+        break;
+      }
+
+      let next_nodes = find_next_node(trials_map, &coord, direction);
+
+      let next_nodes = next_nodes
+        .iter()
+        .filter(|&(_, coord)| trials_map[*coord] != '#')
+        .collect::<Vec<&(Direction, (usize, usize))>>();
+
+      if next_nodes.is_empty() {
+        let current_entry = result
+          .entry(prev_coord)
+          .or_insert_with(HashSet::new);
+
+        current_entry.insert((steps, coord));
+        break;
+      }
+
+      if next_nodes.len() > 1 {
+        {
+          let current_entry = result
+            .entry(prev_coord)
+            .or_insert_with(HashSet::new);
+
+          current_entry.insert((steps, coord));
+
+          for &(next_direction, next_coord) in next_nodes.iter() {
+            if explored.insert((coord, *next_direction)) {
+              queue.push_back((coord, *next_direction, *next_coord));
+            }
+          }
+        }
+
+        break;
+      }
+
+      let &(next_direction, next_coord) = next_nodes[0];
+
+      coord = next_coord;
+      direction = next_direction;
+
+      if let Some(forced_direction) = Direction::from_char(trials_map[coord]) {
+        if forced_direction != direction {
+          break;
+        }
+      }
+    }
+  }
+
+  result
+}
+
+fn build_cyclic_graph(trials_map: &Grid<char>, entrance: (usize, usize)) -> HashMap<(usize, usize), HashMap<(usize, usize), usize>> {
+  let mut result = HashMap::new();
+
+  let mut queue = VecDeque::new();
+  for (next_direction, next_coord) in find_next_node(trials_map, &entrance, Direction::Down) {
+    if trials_map[next_coord] != '#' {
+      queue.push_back((entrance, next_direction, next_coord));
+    }
+  }
+
+  while let Some((prev_coord, mut direction, mut coord)) = queue.pop_front() {
+    let mut steps = 0;
+
+    loop {
+      let next_nodes = find_next_node(trials_map, &coord, direction);
+
+      let next_nodes = next_nodes
+        .iter()
+        .filter(|&(_, coord)| trials_map[*coord] != '#')
+        .collect::<Vec<&(Direction, (usize, usize))>>();
+
+      if next_nodes.is_empty() {
+        let current_entry = result
+          .entry(prev_coord)
+          .or_insert_with(HashMap::new);
+
+        current_entry.insert(coord, steps);
+        break;
+      }
+
+      steps += 1;
+
+      if steps > trials_map.rows() * trials_map.cols() {
+        break;
+      }
+
+      if next_nodes.len() > 1 {
+        let current_entry = result
+          .entry(prev_coord)
+          .or_insert_with(HashMap::new);
+
+        if current_entry.insert(coord, steps).is_none() {
+          for &(next_direction, next_coord) in next_nodes.iter() {
+            queue.push_back((coord, *next_direction, *next_coord));
+          }
+        }
+
+        break;
+      }
+
+      let &(next_direction, next_coord) = next_nodes[0];
+
+      coord = next_coord;
+      direction = next_direction;
+    }
+  }
+
+  result
+}
+
+#[allow(clippy::type_complexity)]
+fn find_longest_path_within_cyclic_graph(graph: &HashMap<(usize, usize), HashMap<(usize, usize), usize>>, entrance: &(usize, usize), exit: &(usize, usize), distance: usize, visited: &HashSet<(usize, usize)>) -> usize {
+  if entrance == exit {
+    return distance + 1;
+  }
+
+  let node = match graph.get(entrance) {
+    Some(node) => node,
+    None => return 0,
+  };
+
+  let mut visited = visited.clone();
+  visited.insert(*entrance);
+
+  let mut max_steps = 0;
+
+  for (coord, &steps) in node.iter() {
+    if visited.contains(coord) {
+      continue;
+    }
+
+    let value = find_longest_path_within_cyclic_graph(graph, coord, exit, distance + steps, &visited);
+
+    max_steps = max_steps.max(value);
+  }
+
+  max_steps
+}
+
+fn find_longest_hike(trials_map: &Grid<char>) -> Result<usize, Error> {
+
+  let entrance_col = match find_openning_column(trials_map.row(0)) {
+    Some(entrance_col) => entrance_col,
+    None => return Err(Error::unsolvable("no entrance in the first row")),
+  };
+
+  let graph = build_graph(trials_map, (0, entrance_col));
+  let edges = |coord: &(usize, usize)| graph.get(coord).into_iter().flatten().map(|&(length, next)| (next, length));
+
+  match dag_longest_paths(&edges, (0, entrance_col)) {
+    Some(lengths) => Ok(lengths.into_values().max().unwrap_or(0)),
+    None => Err(Error::unsolvable("slopes allow walking in a circle")),
+  }
+}
+
+fn find_longest_hike_without_slopes(trials_map: &Grid<char>) -> Result<usize, Error> {
+
+  let entrance_col = match find_openning_column(trials_map.row(0)) {
+    Some(entrance_col) => entrance_col,
+    None => return Err(Error::unsolvable("no entrance in the first row")),
+  };
+
+  let exit_col = match find_openning_column(trials_map.row(trials_map.rows() - 1)) {
+    Some(exit_col) => exit_col,
+    None => return Err(Error::unsolvable("no exit in the last row")),
+  };
+
+  let graph = build_cyclic_graph(trials_map, (0, entrance_col));
+
+  Ok(find_longest_path_within_cyclic_graph(&graph, &(0, entrance_col), &(trials_map.rows() - 1, exit_col), 0, &HashSet::new()))
+}
+
+/// Cells of a trail between two junctions `spacing` apart, as steps along it and steps off to
+/// one side. Most trails take a detour within the first half of their length.
+fn trail_cells(rng: &mut Rng, spacing: usize) -> Vec<(usize, usize)> {
+  let mut cells = (1..spacing).map(|along| (along, 0)).collect::<Vec<(usize, usize)>>();
+
+  if rng.chance(2, 3) {
+    let start = rng.range(2..(spacing / 2 - 2) as i64) as usize;
+    let end = rng.range((start + 2) as i64..(spacing / 2) as i64) as usize;
+    let depth = rng.range(2..(spacing / 2 - 1) as i64) as usize;
+
+    cells.retain(|&(along, _)| along <= start || along >= end);
+
+    for aside in 1..=depth {
+      cells.push((start, aside));
+      cells.push((end, aside));
+    }
+    for along in (start + 1)..end {
+      cells.push((along, depth));
+    }
+  }
+
+  cells
+}
+
+/// A map of `size` by `size` junctions, at most six, joined by winding trails to their right and
+/// below. Slopes around every junction only lead right or down, as in the puzzle.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  let junctions = size.clamp(1, 6);
+  let spacing = 2 * rng.range(5..8) as usize;
+  let at = |index: usize| spacing * (index + 1);
+
+  let dim = at(junctions) + 1;
+  let mut trails = Grid::new(dim, dim, '#');
+
+  for row in 0..at(0) {
+    trails[(row, at(0))] = '.';
+  }
+  for row in at(junctions - 1)..dim {
+    trails[(row, at(junctions - 1))] = '.';
+  }
+
+  for i in 0..junctions {
+    for j in 0..junctions {
+      let (row, col) = (at(i), at(j));
+      trails[(row, col)] = '.';
+
+      if j + 1 < junctions {
+        for (along, aside) in trail_cells(rng, spacing) {
+          trails[(row - aside, col + along)] = '.';
+        }
+      }
+
+      if i + 1 < junctions {
+        for (along, aside) in trail_cells(rng, spacing) {
+          trails[(row + along, col - aside)] = '.';
+        }
+      }
+    }
+  }
+
+  for i in 0..junctions {
+    for j in 0..junctions {
+      let (row, col) = (at(i), at(j));
+
+      for (position, slope) in [((row - 1, col), 'v'), ((row + 1, col), 'v'), ((row, col - 1), '>'), ((row, col + 1), '>')] {
+        if trails[position] == '.' {
+          trails[position] = slope;
+        }
+      }
+    }
+  }
+
+  format!("{trails}\n")
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+  type Input<'a> = Grid<char>;
+
+  type Config = ();
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    match Grid::parse_with(contents, |tile| matches!(tile, '#' | '.' | '^' | '>' | 'v' | '<').then_some(tile)) {
+      Ok(trials_map) if trials_map.is_empty() => Err(Error::parse(1, 0, "missing map")),
+      result => result,
+    }
+  }
+
+  fn part1(trials_map: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    find_longest_hike(trials_map).map(|length| length.into())
+  }
+
+  fn part2(trials_map: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    find_longest_hike_without_slopes(trials_map).map(|length| length.into())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{fuzz::assert_no_panic, generate::assert_solvable};
+
+  use super::*;
+
+  const EXAMPLE: &str = "\
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+";
+
+  #[test]
+  fn part1_example() {
+    let input = Day23::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day23::part1(&input, &()), Ok(Answer::Unsigned(94)));
+  }
+
+  #[test]
+  fn part2_example() {
+    let input = Day23::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day23::part2(&input, &()), Ok(Answer::Unsigned(154)));
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    assert_solvable::<Day23>(generate, 0..10, 3);
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day23>(generate, 0..100, 3);
+  }
+}
@@ -0,0 +1,205 @@
+use aoc_common::{Answer, Error, Solution, config::{self, parse_value}, extract::exactly, parser::{and_then, map, parse_line, rest, separated_pair, tag, take_while}, random::Rng};
+
+pub struct Hailstone  {
+  px: f64,
+  py: f64,
+  #[allow(dead_code)]
+  pz: f64,
+  vx: f64,
+  vy: f64,
+  #[allow(dead_code)]
+  vz: f64,
+}
+
+fn parse_hailstones(contents: &str) -> Result<Vec<Hailstone>, Error> {
+  let position = and_then(take_while(|char| char != '@'), exactly::<i64, 3>);
+  let velocity = and_then(rest, exactly::<i64, 3>);
+
+  let hailstone = map(separated_pair(position, tag("@"), velocity), |([px, py, pz], [vx, vy, vz])| {
+    Hailstone{ px: px as f64, py: py as f64, pz: pz as f64, vx: vx as f64, vy: vy as f64, vz: vz as f64 }
+  });
+
+  contents
+    .lines()
+    .enumerate()
+    .filter(|(_, line)| !line.trim().is_empty())
+    .map(|(index, line)| parse_line(index + 1, line, &hailstone))
+    .collect()
+}
+
+fn intersection(a: &Hailstone, b: &Hailstone) -> (f64, f64) {
+  let x1 = a.px;
+  let x2 = a.px + a.vx * 100000000000000f64;
+
+  let y1 = a.py;
+  let y2 = a.py + a.vy * 100000000000000f64;
+
+  let x3 = b.px;
+  let x4 = b.px + b.vx * 100000000000000f64;
+
+  let y3 = b.py;
+  let y4 = b.py + b.vy * 100000000000000f64;
+
+  let px = ((x1*y2-y1*x2)*(x3-x4)-(x1-x2)*(x3*y4-y3*x4)) / ((x1-x2)*(y3-y4)-(y1-y2)*(x3-x4));
+  let py = ((x1*y2-y1*x2)*(y3-y4)-(y1-y2)*(x3*y4-y3*x4)) / ((x1-x2)*(y3-y4)-(y1-y2)*(x3-x4));
+
+  (px, py)
+}
+
+fn contains(limits: (f64, f64), point: (f64, f64)) -> bool {
+  point.0 >= limits.0 && point.0 <= limits.1 && point.1 >= limits.0 && point.1 <= limits.1
+}
+
+fn is_not_past(s: &Hailstone, p: (f64, f64)) -> bool {
+ (p.0 - s.px) / s.vx >= 0f64 && (p.1 - s.py) / s.vy >= 0f64
+}
+
+fn count_intersections_within_test_area(hailstones: &[Hailstone], limits: (f64, f64)) -> usize {
+  let mut passed = 0;
+
+  for i in 0..hailstones.len() {
+    for j in (i + 1)..hailstones.len() {
+      let p = intersection(&hailstones[i], &hailstones[j]);
+
+      if contains(limits, p) && is_not_past(&hailstones[i], p) && is_not_past(&hailstones[j], p) {
+        passed += 1;
+      }
+    }
+  }
+
+  passed
+}
+
+/// `size` hailstones that a single rock thrown from somewhere in the test area would hit, each at
+/// its own time, as in the puzzle.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+  let rock_position = [(); 3].map(|_| rng.range(200_000_000_000_000..400_000_000_000_000));
+  let rock_velocity = [(); 3].map(|_| rng.range(-300..301));
+
+  let mut contents = String::new();
+
+  for _ in 0..size {
+    let time = rng.range(10_000_000_000..300_000_000_000);
+    let velocity = rock_velocity.map(|speed| loop {
+      let candidate = rng.range(-600..601);
+      if candidate != speed {
+        break candidate;
+      }
+    });
+
+    let position = [0, 1, 2].map(|axis| rock_position[axis] + (rock_velocity[axis] - velocity[axis]) * time);
+
+    contents.push_str(&format!(
+      "{}, {}, {} @ {}, {}, {}\n",
+      position[0], position[1], position[2], velocity[0], velocity[1], velocity[2],
+    ));
+  }
+
+  contents
+}
+
+pub struct Config {
+  /// The range both coordinates of a crossing must lie in for part 1.
+  pub test_area: (f64, f64),
+}
+
+impl Default for Config {
+  fn default() -> Self {
+    Config{ test_area: (200000000000000f64, 400000000000000f64) }
+  }
+}
+
+impl config::Config for Config {
+  const FLAGS: &'static [(&'static str, &'static str)] = &[("test-area", "min..max")];
+
+  fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+    let (min, max) = match value.split_once("..") {
+      Some(bounds) => bounds,
+      None => return Err(format!("invalid value \"{value}\" for \"--{name}\": expected \"<min>..<max>\"")),
+    };
+
+    match (parse_value::<f64>(name, min), parse_value::<f64>(name, max)) {
+      (Ok(min), Ok(max)) if min <= max => {
+        self.test_area = (min, max);
+        Ok(())
+      },
+      (Ok(_), Ok(_)) => Err(format!("invalid value \"{value}\" for \"--{name}\": the minimum is above the maximum")),
+      (Err(error), _) | (_, Err(error)) => Err(error),
+    }
+  }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+  type Input<'a> = Vec<Hailstone>;
+
+  type Config = Config;
+
+  fn parse<'a>(contents: &'a str) -> Result<Self::Input<'a>, Error> {
+    parse_hailstones(contents)
+  }
+
+  fn part1(hailstones: &Self::Input<'_>, config: &Self::Config) -> Result<Answer, Error> {
+    Ok(count_intersections_within_test_area(hailstones, config.test_area).into())
+  }
+
+  fn part2(_hailstones: &Self::Input<'_>, _: &Self::Config) -> Result<Answer, Error> {
+    Err(Error::NotImplemented)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_common::{config::from_flags, fuzz::assert_no_panic};
+
+  use super::*;
+
+  const EXAMPLE: &str = "\
+19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3
+";
+
+  #[test]
+  fn test_area_example() {
+    let hailstones = Day24::parse(EXAMPLE).unwrap();
+
+    assert_eq!(Day24::part1(&hailstones, &Config{ test_area: (7f64, 27f64) }), Ok(Answer::Unsigned(2)));
+  }
+
+  #[test]
+  fn test_area_flag_takes_a_range() {
+    let flags = |value: &str| vec![(String::from("test-area"), value.to_string())];
+
+    assert_eq!(from_flags::<Config>(&flags("7..27")).map(|config| config.test_area), Ok((7f64, 27f64)));
+    assert!(from_flags::<Config>(&flags("27..7")).is_err());
+    assert!(from_flags::<Config>(&flags("7")).is_err());
+  }
+
+  #[test]
+  fn stray_characters_between_numbers_are_refused() {
+    let result = Day24::parse("19, 13, 30 @ -2,  1, -2x");
+    assert_eq!(result.err(), Some(Error::parse(1, 24, "expected whitespace or \",\" after a number").spanning(1)));
+
+    let result = Day24::parse("19; 13, 30 @ -2,  1, -2");
+    assert_eq!(result.err(), Some(Error::parse(1, 3, "expected whitespace or \",\" after a number").spanning(1)));
+  }
+
+  #[test]
+  fn generated_inputs_are_solvable() {
+    for seed in 0..20 {
+      let hailstones = Day24::parse(&generate(&mut Rng::new(seed), 50)).unwrap();
+
+      assert!(Day24::part1(&hailstones, &Config::default()).is_ok());
+      assert_eq!(Day24::part2(&hailstones, &Config::default()), Err(Error::NotImplemented));
+    }
+  }
+
+  #[test]
+  fn arbitrary_inputs_do_not_panic() {
+    assert_no_panic::<Day24>(generate, 0..100, 50);
+  }
+}